@@ -0,0 +1,697 @@
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Ident, LitInt, LitStr, Type};
+
+/// Implements `TransmuteGuard` and `SafeTransmuteFrom` in both directions between a
+/// `#[repr(transparent)]` struct with one field and the type of the field.
+///
+/// Generic structs only get the impls from the field to the struct, the orphan rules forbid the
+/// other direction. A field of type `[T]`, `str` or `dyn Trait` gets `SafeTransmuteRefFrom` and,
+/// on non-generic structs, `SafeTransmuteMutFrom` instead of `SafeTransmuteFrom`, since the blanket
+/// impls only cover sized types. A generic field only gets `SafeTransmuteFrom` where it is `Sized`.
+///
+/// `#[transmute_guard(from = "Other")]` on a `#[repr(C)]` struct instead implements both traits
+/// from `Other`, after checking at compile time that `Other` has the same size, at least the same
+/// alignment and the same fields at the same offsets, and that the type of every field implements
+/// `TransmuteGuard` of the other field.
+#[proc_macro_derive(TransmuteGuard, attributes(transmute_guard))]
+pub fn derive_transmute_guard(item: TokenStream) -> TokenStream {
+    syn::parse(item)
+        .and_then(expand_transmute_guard)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_field_wise(input: &DeriveInput, c: bool, sources: &[Type]) -> syn::Result<TokenStream2> {
+    if !c {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[transmute_guard(from = \"...\")]` requires `#[repr(C)]`",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`#[transmute_guard(from = \"...\")]` does not support generic structs",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(TransmuteGuard)]` can only be applied to structs",
+        ));
+    };
+
+    let name = &input.ident;
+    let members: Vec<_> = data.fields.members().collect();
+    let bindings: Vec<_> = (0..members.len())
+        .map(|index| quote::format_ident!("field{index}"))
+        .collect();
+    let types = data.fields.iter().map(|field| &field.ty);
+    let mut output = TokenStream2::new();
+    for source in sources {
+        let types = types.clone();
+        output.extend(quote! {
+            const _: () = {
+                fn guard<T: ::transmute_guard::TransmuteGuard<U>, U>(_: U) {}
+                #[allow(unused)]
+                fn fields(value: #source) {
+                    let #source { #(#members: #bindings),* } = value;
+                    #(guard::<#types, _>(#bindings);)*
+                }
+                ::core::assert!(
+                    ::core::mem::size_of::<#name>() == ::core::mem::size_of::<#source>(),
+                    ::core::concat!(
+                        "`",
+                        ::core::stringify!(#name),
+                        "` and `",
+                        ::core::stringify!(#source),
+                        "` have different sizes",
+                    ),
+                );
+                ::core::assert!(
+                    ::core::mem::align_of::<#source>() >= ::core::mem::align_of::<#name>(),
+                    ::core::concat!(
+                        "`",
+                        ::core::stringify!(#name),
+                        "` is more aligned than `",
+                        ::core::stringify!(#source),
+                        "`",
+                    ),
+                );
+                #(
+                    ::core::assert!(
+                        ::core::mem::offset_of!(#name, #members)
+                            == ::core::mem::offset_of!(#source, #members),
+                        ::core::concat!(
+                            "`",
+                            ::core::stringify!(#members),
+                            "` has different offsets in `",
+                            ::core::stringify!(#name),
+                            "` and `",
+                            ::core::stringify!(#source),
+                            "`",
+                        ),
+                    );
+                )*
+            };
+
+            unsafe impl ::transmute_guard::TransmuteGuard<#source> for #name {}
+            unsafe impl ::transmute_guard::SafeTransmuteFrom<#source> for #name {
+                #[inline]
+                fn safe_transmute_from(value: #source) -> Self {
+                    ::transmute_guard::safe_transmute_const(value)
+                }
+            }
+        });
+    }
+    Ok(output)
+}
+
+fn expand_transmute_guard(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut transparent = false;
+    let mut c = false;
+    let mut sources = Vec::new();
+    for attr in &input.attrs {
+        if attr.path().is_ident("repr") {
+            attr.parse_nested_meta(|meta| {
+                transparent |= meta.path.is_ident("transparent");
+                c |= meta.path.is_ident("C");
+                if meta.input.peek(syn::token::Paren) {
+                    meta.input.parse::<proc_macro2::Group>()?;
+                }
+                Ok(())
+            })?;
+        } else if attr.path().is_ident("transmute_guard") {
+            attr.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("from") {
+                    return Err(meta.error("expected `from = \"...\"`"));
+                }
+                let value = meta.value()?;
+                sources.push(if value.peek(LitStr) {
+                    value.parse::<LitStr>()?.parse::<Type>()?
+                } else {
+                    value.parse::<Type>()?
+                });
+                Ok(())
+            })?;
+        }
+    }
+    if !sources.is_empty() {
+        return expand_field_wise(&input, c, &sources);
+    }
+    if !transparent {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(TransmuteGuard)]` requires `#[repr(transparent)]`",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(TransmuteGuard)]` can only be applied to structs",
+        ));
+    };
+    let mut fields = data.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(Error::new(
+            data.fields.span(),
+            "`#[derive(TransmuteGuard)]` requires exactly one field",
+        ));
+    };
+
+    let name = &input.ident;
+    let inner = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let unsized_inner = matches!(inner, Type::Slice(_) | Type::TraitObject(_))
+        || matches!(inner, Type::Path(path) if path.path.is_ident("str"));
+    let generic = !input.generics.params.is_empty();
+    let member = data.fields.members().next();
+    // `dyn Trait` is `'static` in the impl header, but would borrow the lifetime of the reference
+    // in the signatures
+    let sig_inner: Type = match inner {
+        Type::TraitObject(object)
+            if !object
+                .bounds
+                .iter()
+                .any(|bound| matches!(bound, syn::TypeParamBound::Lifetime(_))) =>
+        {
+            syn::parse_quote!((#inner + 'static))
+        }
+        _ => inner.clone(),
+    };
+
+    let mut output = quote! {
+        unsafe impl #impl_generics ::transmute_guard::TransmuteGuard<#inner>
+            for #name #ty_generics #where_clause {}
+    };
+    if unsized_inner {
+        // The blanket ref and mut impls only cover sized types, and the pointer casts keep the
+        // length or vtable of the field
+        output.extend(quote! {
+            unsafe impl #impl_generics ::transmute_guard::SafeTransmuteRefFrom<#inner>
+                for #name #ty_generics #where_clause
+            {
+                #[inline]
+                fn safe_transmute_ref_from<'value>(value: &'value #sig_inner) -> &'value Self {
+                    let from_ptr: *const #sig_inner = ::core::ptr::from_ref(value);
+                    unsafe { &*(from_ptr as *const Self) }
+                }
+            }
+        });
+    } else {
+        // A generic field may be `?Sized`, which can only be transmuted by value once it is sized
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#inner: ::core::marker::Sized));
+        let where_clause = &generics.where_clause;
+        output.extend(quote! {
+            unsafe impl #impl_generics ::transmute_guard::SafeTransmuteFrom<#inner>
+                for #name #ty_generics #where_clause
+            {
+                #[inline]
+                fn safe_transmute_from(value: #inner) -> Self {
+                    ::transmute_guard::safe_transmute_const(value)
+                }
+            }
+        });
+    }
+    if generic {
+        return Ok(output);
+    }
+    output.extend(quote! {
+        unsafe impl ::transmute_guard::TransmuteGuard<#name> for #inner #where_clause {}
+    });
+    if unsized_inner {
+        output.extend(quote! {
+            unsafe impl ::transmute_guard::SafeTransmuteMutFrom<#inner> for #name #where_clause {
+                #[inline]
+                fn safe_transmute_mut_from<'value>(
+                    value: &'value mut #sig_inner,
+                ) -> &'value mut Self {
+                    let from_ptr: *mut #sig_inner = ::core::ptr::from_mut(value);
+                    unsafe { &mut *(from_ptr as *mut Self) }
+                }
+            }
+
+            unsafe impl ::transmute_guard::SafeTransmuteRefFrom<#name> for #inner #where_clause {
+                #[inline]
+                fn safe_transmute_ref_from<'value>(value: &'value #name) -> &'value Self {
+                    &value.#member
+                }
+            }
+
+            unsafe impl ::transmute_guard::SafeTransmuteMutFrom<#name> for #inner #where_clause {
+                #[inline]
+                fn safe_transmute_mut_from<'value>(value: &'value mut #name) -> &'value mut Self {
+                    &mut value.#member
+                }
+            }
+        });
+    } else {
+        output.extend(quote! {
+            unsafe impl ::transmute_guard::SafeTransmuteFrom<#name> for #inner #where_clause {
+                #[inline]
+                fn safe_transmute_from(value: #name) -> Self {
+                    ::transmute_guard::safe_transmute_const(value)
+                }
+            }
+        });
+    }
+    Ok(output)
+}
+
+/// Implements `Pod` and the transmute guards in both directions with the byte array of the same
+/// size for a `#[repr(C, packed)]` struct whose fields are all `Pod`.
+///
+/// Every field `name` also gets const accessors `name` and `set_name` that copy the value, since
+/// references to the fields would not be aligned.
+#[proc_macro_derive(Packed)]
+pub fn derive_packed(item: TokenStream) -> TokenStream {
+    syn::parse(item)
+        .and_then(expand_packed)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_packed(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut c = false;
+    let mut packed = false;
+    for attr in &input.attrs {
+        if attr.path().is_ident("repr") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("packed") {
+                    packed = true;
+                    if meta.input.peek(syn::token::Paren) {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        packed = content.parse::<LitInt>()?.base10_parse::<usize>()? == 1;
+                    }
+                } else {
+                    c |= meta.path.is_ident("C");
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.input.parse::<proc_macro2::Group>()?;
+                    }
+                }
+                Ok(())
+            })?;
+        }
+    }
+    if !c || !packed {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(Packed)]` requires `#[repr(C, packed)]`",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`#[derive(Packed)]` does not support generic structs",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(Packed)]` can only be applied to structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "`#[derive(Packed)]` requires named fields",
+        ));
+    };
+
+    let name = &input.ident;
+    let types: Vec<_> = fields.named.iter().map(|field| &field.ty).collect();
+    let accessors = fields.named.iter().map(|field| {
+        let vis = &field.vis;
+        let ident = field.ident.as_ref().unwrap();
+        let setter = quote::format_ident!("set_{ident}");
+        let ty = &field.ty;
+        quote! {
+            #[inline]
+            #vis const fn #ident(&self) -> #ty {
+                self.#ident
+            }
+
+            #[inline]
+            #vis const fn #setter(&mut self, value: #ty) {
+                self.#ident = value;
+            }
+        }
+    });
+    let bytes = quote!([u8; ::core::mem::size_of::<#name>()]);
+    Ok(quote! {
+        const _: () = {
+            fn pod<T: ::transmute_guard::Pod>() {}
+            #[allow(unused)]
+            fn fields() {
+                #(pod::<#types>();)*
+            }
+        };
+
+        unsafe impl ::transmute_guard::Zeroable for #name {}
+        unsafe impl ::transmute_guard::Pod for #name {}
+        unsafe impl ::transmute_guard::TransmuteGuard<#bytes> for #name {}
+        unsafe impl ::transmute_guard::TransmuteGuard<#name> for #bytes {}
+        unsafe impl ::transmute_guard::SafeTransmuteFrom<#bytes> for #name {
+            #[inline]
+            fn safe_transmute_from(value: #bytes) -> Self {
+                ::transmute_guard::safe_transmute_const(value)
+            }
+        }
+        unsafe impl ::transmute_guard::SafeTransmuteFrom<#name> for #bytes {
+            #[inline]
+            fn safe_transmute_from(value: #name) -> Self {
+                ::transmute_guard::safe_transmute_const(value)
+            }
+        }
+
+        impl #name {
+            #(#accessors)*
+        }
+    })
+}
+
+/// Implements `TryFromBytes` for a `#[repr(C)]` or `#[repr(transparent)]` struct whose fields all
+/// implement `TryFromBytes`, or for an enum with unit variants and an integer `repr`.
+#[proc_macro_derive(TryFromBytes)]
+pub fn derive_try_from_bytes(item: TokenStream) -> TokenStream {
+    syn::parse(item)
+        .and_then(expand_try_from_bytes)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_try_from_bytes(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut c = false;
+    let mut int = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("repr") {
+            attr.parse_nested_meta(|meta| {
+                const INTS: [&str; 12] = [
+                    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128",
+                    "isize",
+                ];
+                c |= meta.path.is_ident("C") || meta.path.is_ident("transparent");
+                if let Some(ident) = meta.path.get_ident() {
+                    if INTS.iter().any(|int| ident == int) {
+                        int = Some(ident.clone());
+                    }
+                }
+                if meta.input.peek(syn::token::Paren) {
+                    meta.input.parse::<proc_macro2::Group>()?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`#[derive(TryFromBytes)]` does not support generic types",
+        ));
+    }
+
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if !c {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "`#[derive(TryFromBytes)]` requires `#[repr(C)]` or `#[repr(transparent)]`",
+                ));
+            }
+            let members = data.fields.members();
+            let types = data.fields.iter().map(|field| &field.ty);
+            quote! {
+                #(
+                    let offset = ::core::mem::offset_of!(#name, #members);
+                    <#types as ::transmute_guard::TryFromBytes>::validate_bytes(
+                        &bytes[offset..][..::core::mem::size_of::<#types>()],
+                    )
+                    .map_err(|index| offset + index)?;
+                )*
+                ::core::result::Result::Ok(())
+            }
+        }
+        Data::Enum(data) => {
+            let Some(int) = int else {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "`#[derive(TryFromBytes)]` requires an integer `repr` on enums",
+                ));
+            };
+            if let Some(variant) = data
+                .variants
+                .iter()
+                .find(|variant| !matches!(variant.fields, Fields::Unit))
+            {
+                return Err(Error::new(
+                    variant.fields.span(),
+                    "`#[derive(TryFromBytes)]` requires unit variants",
+                ));
+            }
+            let variants = data.variants.iter().map(|variant| &variant.ident);
+            quote! {
+                let mut value = [0; ::core::mem::size_of::<#int>()];
+                value.copy_from_slice(bytes);
+                let value = #int::from_ne_bytes(value);
+                if false #(|| value == Self::#variants as #int)* {
+                    ::core::result::Result::Ok(())
+                } else {
+                    ::core::result::Result::Err(0)
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.ident.span(),
+                "`#[derive(TryFromBytes)]` can only be applied to structs and enums",
+            ));
+        }
+    };
+    Ok(quote! {
+        unsafe impl ::transmute_guard::TryFromBytes for #name {
+            #[inline]
+            fn validate_bytes(bytes: &[u8]) -> ::core::result::Result<(), usize> {
+                #body
+            }
+        }
+    })
+}
+
+/// Declares `NameView<'a>` with the same fields as references into a byte buffer, where the fields
+/// are laid out one after another without padding.
+///
+/// `NameView::parse` checks the length of the buffer and the alignment and bit pattern of every
+/// field, which all have to implement `TryFromBytes`. An invalid bit pattern is reported with the
+/// offset of the first invalid byte in the buffer.
+#[proc_macro_derive(View)]
+pub fn derive_view(item: TokenStream) -> TokenStream {
+    syn::parse(item)
+        .and_then(expand_view)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_view(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "`#[derive(View)]` does not support generic structs",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`#[derive(View)]` can only be applied to structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "`#[derive(View)]` requires named fields",
+        ));
+    };
+
+    let vis = &input.vis;
+    let view = quote::format_ident!("{}View", input.ident);
+    let doc = format!("Borrowed view of the bytes of a [`{}`]", input.ident);
+    let field_vis: Vec<_> = fields.named.iter().map(|field| &field.vis).collect();
+    let idents: Vec<_> = fields.named.iter().map(|field| &field.ident).collect();
+    let types: Vec<_> = fields.named.iter().map(|field| &field.ty).collect();
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy)]
+        #vis struct #view<'a> {
+            #(#field_vis #idents: &'a #types,)*
+        }
+
+        impl<'a> #view<'a> {
+            /// Size of the bytes of all fields
+            #vis const SIZE: usize = 0 #(+ ::core::mem::size_of::<#types>())*;
+
+            #vis fn parse(
+                bytes: &'a [u8],
+            ) -> ::core::result::Result<Self, ::transmute_guard::CastError> {
+                if bytes.len() != Self::SIZE {
+                    return ::core::result::Result::Err(
+                        ::transmute_guard::CastError::LengthMismatch(
+                            ::transmute_guard::LengthError {
+                                len: bytes.len(),
+                                size: Self::SIZE,
+                            },
+                        ),
+                    );
+                }
+                #(
+                    let offset = Self::SIZE - bytes.len();
+                    let (field, bytes) = bytes.split_at(::core::mem::size_of::<#types>());
+                    let #idents = match ::transmute_guard::try_from_bytes::<#types>(field) {
+                        ::core::result::Result::Ok(field) => field,
+                        ::core::result::Result::Err(::transmute_guard::CastError::Invalid(
+                            ::transmute_guard::ValidityError { index },
+                        )) => {
+                            return ::core::result::Result::Err(
+                                ::transmute_guard::CastError::Invalid(
+                                    ::transmute_guard::ValidityError {
+                                        index: offset + index,
+                                    },
+                                ),
+                            );
+                        }
+                        ::core::result::Result::Err(err) => {
+                            return ::core::result::Result::Err(err);
+                        }
+                    };
+                )*
+                let _ = bytes;
+                ::core::result::Result::Ok(Self { #(#idents),* })
+            }
+        }
+    })
+}
+
+/// Declares the annotated enum as an alias of `parent` with the same output as `enum_alias!`, e.g.
+/// `#[enum_alias(parent = "Parent", repr = "i32", display)]`.
+///
+/// `repr` defaults to `u8` and every other argument is passed on as an option of `enum_alias!`.
+#[proc_macro_attribute]
+pub fn enum_alias(attr: TokenStream, item: TokenStream) -> TokenStream {
+    expand_enum_alias(attr.into(), item.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_enum_alias(attr: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    let mut parent = None;
+    let mut repr = None;
+    let mut opts = Vec::new();
+    for arg in split_args(attr) {
+        match arg.first() {
+            Some(TokenTree::Ident(key)) if key == "parent" => {
+                parent = Some(parse_value::<Type>(key, &arg)?);
+            }
+            Some(TokenTree::Ident(key)) if key == "repr" => {
+                repr = Some(parse_value::<Ident>(key, &arg)?);
+            }
+            _ => opts.push(arg.into_iter().collect::<TokenStream2>()),
+        }
+    }
+    let parent = parent.ok_or_else(|| {
+        Error::new(
+            proc_macro2::Span::call_site(),
+            "missing `parent = \"...\"` argument",
+        )
+    })?;
+    let repr = repr.map(|repr| quote!(as #repr));
+
+    let input: DeriveInput = syn::parse2(item)?;
+    if !input.generics.params.is_empty() || input.generics.where_clause.is_some() {
+        return Err(Error::new(
+            input.generics.span(),
+            "enum aliases cannot be generic",
+        ));
+    }
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`enum_alias` can only be applied to enums",
+        ));
+    };
+
+    let mut errors: Option<Error> = None;
+    let mut push = |error: Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            push(Error::new(
+                variant.fields.span(),
+                format_args!("`{}` must be a unit variant like its parent", variant.ident),
+            ));
+        }
+        if let Some((_, discriminant)) = &variant.discriminant {
+            push(Error::new(
+                discriminant.span(),
+                format_args!(
+                    "the discriminant of `{}` is taken from the parent",
+                    variant.ident
+                ),
+            ));
+        }
+    }
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let name = &input.ident;
+    let variants = data.variants.iter().map(|variant| {
+        let attrs = &variant.attrs;
+        let ident = &variant.ident;
+        quote!(#(#attrs)* #ident)
+    });
+    Ok(quote! {
+        ::transmute_guard::enum_alias! {
+            #(#[enum_alias(#opts)])*
+            #(#attrs)*
+            #vis enum #name: #parent #repr = { #(#variants)|* };
+        }
+    })
+}
+
+fn split_args(attr: TokenStream2) -> Vec<Vec<TokenTree>> {
+    let mut args = vec![Vec::new()];
+    for token in attr {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(Vec::new()),
+            _ => args.last_mut().unwrap().push(token),
+        }
+    }
+    args.retain(|arg| !arg.is_empty());
+    args
+}
+
+fn parse_value<T: syn::parse::Parse + ToTokens>(key: &Ident, arg: &[TokenTree]) -> syn::Result<T> {
+    match arg {
+        [_, TokenTree::Punct(eq), value @ ..] if eq.as_char() == '=' && !value.is_empty() => {
+            let value: TokenStream2 = value.iter().cloned().collect();
+            match syn::parse2::<LitStr>(value.clone()) {
+                Ok(lit) => lit.parse(),
+                Err(_) => syn::parse2(value),
+            }
+        }
+        _ => Err(Error::new(
+            key.span(),
+            format_args!("expected `{key} = \"...\"`"),
+        )),
+    }
+}
@@ -0,0 +1,26 @@
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_alias_arbitrary {
+    { $name:ident } => {
+        impl<'a> $crate::__private::arbitrary::Arbitrary<'a> for $name {
+            #[inline]
+            fn arbitrary(
+                u: &mut $crate::__private::arbitrary::Unstructured<'a>,
+            ) -> $crate::__private::arbitrary::Result<Self> {
+                u.choose(&Self::VARIANTS).copied()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_alias_arbitrary {
+    { $($alias:tt)* } => {
+        ::core::compile_error!(
+            "the `arbitrary` option of `enum_alias!` requires the `arbitrary` feature of `transmute_guard`"
+        );
+    };
+}
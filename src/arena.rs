@@ -0,0 +1,95 @@
+use core::mem::{align_of, size_of, MaybeUninit};
+
+use crate::{zeroed, CastError, LengthError, Pod};
+
+/// Hands out `Pod` values from a byte buffer, skipping bytes as needed to align every value.
+///
+/// Only `Pod` values are handed out, so the bytes stay initialized when the buffer is used as
+/// `&mut [u8]` again. A failed allocation leaves the arena unchanged.
+#[derive(Debug)]
+pub struct Arena<'a> {
+    bytes: &'a mut [MaybeUninit<u8>],
+    used: usize,
+}
+
+impl<'a> Arena<'a> {
+    #[inline]
+    pub const fn new(bytes: &'a mut [u8]) -> Self {
+        let len = bytes.len();
+        Self::from_uninit(unsafe {
+            core::slice::from_raw_parts_mut(bytes.as_mut_ptr().cast(), len)
+        })
+    }
+
+    #[inline]
+    pub const fn from_uninit(bytes: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self { bytes, used: 0 }
+    }
+
+    /// Number of bytes handed out or skipped for alignment so far
+    #[inline]
+    pub const fn used(&self) -> usize {
+        self.used
+    }
+
+    /// Number of bytes left, some of which may be skipped to align the next value
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn take<T>(&mut self, len: usize) -> Result<&'a mut [MaybeUninit<T>], CastError> {
+        let offset = self.bytes.as_ptr().align_offset(align_of::<T>());
+        let needed = len.saturating_mul(size_of::<T>()).saturating_add(offset);
+        if needed > self.bytes.len() {
+            return Err(CastError::OutOfBounds(LengthError {
+                len: self.bytes.len(),
+                size: needed,
+            }));
+        }
+        let (head, tail) = core::mem::take(&mut self.bytes).split_at_mut(needed);
+        self.bytes = tail;
+        self.used += needed;
+        let ptr = head[offset..].as_mut_ptr().cast();
+        Ok(unsafe { core::slice::from_raw_parts_mut(ptr, len) })
+    }
+
+    #[inline]
+    pub fn alloc<T>(&mut self, value: T) -> Result<&'a mut T, CastError>
+    where
+        T: Pod,
+    {
+        let slots = self.take::<T>(1)?;
+        Ok(slots[0].write(value))
+    }
+
+    #[inline]
+    pub fn alloc_zeroed<T>(&mut self) -> Result<&'a mut T, CastError>
+    where
+        T: Pod,
+    {
+        self.alloc(zeroed())
+    }
+
+    #[inline]
+    pub fn alloc_slice_zeroed<T>(&mut self, len: usize) -> Result<&'a mut [T], CastError>
+    where
+        T: Pod,
+    {
+        let slots = self.take::<T>(len)?;
+        slots.fill(MaybeUninit::new(zeroed()));
+        Ok(unsafe { core::slice::from_raw_parts_mut(slots.as_mut_ptr().cast(), slots.len()) })
+    }
+
+    #[inline]
+    pub fn alloc_slice_copy<T>(&mut self, values: &[T]) -> Result<&'a mut [T], CastError>
+    where
+        T: Pod,
+    {
+        let slots = self.take::<T>(values.len())?;
+        for (slot, value) in slots.iter_mut().zip(values) {
+            slot.write(*value);
+        }
+        Ok(unsafe { core::slice::from_raw_parts_mut(slots.as_mut_ptr().cast(), slots.len()) })
+    }
+}
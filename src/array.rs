@@ -0,0 +1,122 @@
+use core::mem::{size_of, size_of_val};
+
+use crate::{transmute_unchecked, CastError, LengthError};
+
+#[inline]
+const fn assert_flat_len<const N: usize, const M: usize, const L: usize>() {
+    const {
+        assert!(
+            N * M == L,
+            "flattened array length must be the product of both dimensions"
+        );
+    }
+}
+
+#[inline]
+pub const fn flatten_array<T, const N: usize, const M: usize, const L: usize>(
+    src: [[T; N]; M],
+) -> [T; L] {
+    assert_flat_len::<N, M, L>();
+    unsafe { transmute_unchecked(src) }
+}
+
+#[inline]
+pub const fn flatten_array_ref<T, const N: usize, const M: usize, const L: usize>(
+    src: &[[T; N]; M],
+) -> &[T; L] {
+    assert_flat_len::<N, M, L>();
+    let src_ptr = core::ptr::from_ref(src);
+    unsafe { &*src_ptr.cast() }
+}
+
+#[inline]
+pub const fn flatten_array_mut<T, const N: usize, const M: usize, const L: usize>(
+    src: &mut [[T; N]; M],
+) -> &mut [T; L] {
+    assert_flat_len::<N, M, L>();
+    let src_ptr = core::ptr::from_mut(src);
+    unsafe { &mut *src_ptr.cast() }
+}
+
+#[inline]
+pub const fn unflatten_array<T, const N: usize, const M: usize, const L: usize>(
+    src: [T; L],
+) -> [[T; N]; M] {
+    assert_flat_len::<N, M, L>();
+    unsafe { transmute_unchecked(src) }
+}
+
+#[inline]
+pub const fn unflatten_array_ref<T, const N: usize, const M: usize, const L: usize>(
+    src: &[T; L],
+) -> &[[T; N]; M] {
+    assert_flat_len::<N, M, L>();
+    let src_ptr = core::ptr::from_ref(src);
+    unsafe { &*src_ptr.cast() }
+}
+
+#[inline]
+pub const fn unflatten_array_mut<T, const N: usize, const M: usize, const L: usize>(
+    src: &mut [T; L],
+) -> &mut [[T; N]; M] {
+    assert_flat_len::<N, M, L>();
+    let src_ptr = core::ptr::from_mut(src);
+    unsafe { &mut *src_ptr.cast() }
+}
+
+/// # Panics
+/// Panics if `T` is zero sized and the length overflows `usize`, like
+/// [`as_flattened`](slice::as_flattened)
+#[inline]
+pub const fn flatten_slice<T, const N: usize>(src: &[[T; N]]) -> &[T] {
+    unsafe { core::slice::from_raw_parts(src.as_ptr().cast(), flat_len::<N>(src.len())) }
+}
+
+/// # Panics
+/// Same as [`flatten_slice`]
+#[inline]
+pub const fn flatten_slice_mut<T, const N: usize>(src: &mut [[T; N]]) -> &mut [T] {
+    unsafe { core::slice::from_raw_parts_mut(src.as_mut_ptr().cast(), flat_len::<N>(src.len())) }
+}
+
+// Only zero sized `T`s can overflow, the slice of any other `T` fits in `isize::MAX` bytes
+#[inline]
+const fn flat_len<const N: usize>(len: usize) -> usize {
+    match len.checked_mul(N) {
+        Some(len) => len,
+        None => panic!("slice length overflow"),
+    }
+}
+
+#[inline]
+const fn unflatten_len<T, const N: usize>(src: &[T]) -> Result<usize, CastError> {
+    if N == 0 {
+        return Err(CastError::SizeMismatch);
+    }
+    if !src.len().is_multiple_of(N) {
+        return Err(CastError::LengthMismatch(LengthError {
+            len: size_of_val(src),
+            size: size_of::<[T; N]>(),
+        }));
+    }
+    Ok(src.len() / N)
+}
+
+/// The chunks of `N` elements of `src`, which fails if its length is not a multiple of `N`
+#[inline]
+pub const fn try_unflatten_slice<T, const N: usize>(src: &[T]) -> Result<&[[T; N]], CastError> {
+    match unflatten_len::<T, N>(src) {
+        Ok(len) => Ok(unsafe { core::slice::from_raw_parts(src.as_ptr().cast(), len) }),
+        Err(error) => Err(error),
+    }
+}
+
+#[inline]
+pub const fn try_unflatten_slice_mut<T, const N: usize>(
+    src: &mut [T],
+) -> Result<&mut [[T; N]], CastError> {
+    match unflatten_len::<T, N>(src) {
+        Ok(len) => Ok(unsafe { core::slice::from_raw_parts_mut(src.as_mut_ptr().cast(), len) }),
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,35 @@
+use arrayvec::{ArrayString, ArrayVec};
+
+use crate::{safe_transmute, specialize::try_identity, SafeTransmuteFrom, ValidityError};
+
+/// Transmutes every element of `src` and keeps the length and capacity
+#[inline]
+pub fn safe_transmute_array_vec<Src, Dst, const N: usize>(src: ArrayVec<Src, N>) -> ArrayVec<Dst, N>
+where
+    Dst: SafeTransmuteFrom<Src>,
+{
+    let src = match try_identity(src) {
+        Ok(dst) => return dst,
+        Err(src) => src,
+    };
+    src.into_iter().map(safe_transmute).collect()
+}
+
+/// Checks that `src` is UTF-8 and converts it to an `ArrayString` with the same capacity
+#[inline]
+pub fn try_transmute_array_string<const N: usize>(
+    src: ArrayVec<u8, N>,
+) -> Result<ArrayString<N>, ValidityError> {
+    let str = core::str::from_utf8(&src).map_err(|err| ValidityError {
+        index: err.valid_up_to(),
+    })?;
+    let mut dst = ArrayString::new();
+    dst.push_str(str);
+    Ok(dst)
+}
+
+/// Converts `src` to its bytes with the same capacity, which is always valid unlike the inverse
+#[inline]
+pub fn safe_transmute_array_string_bytes<const N: usize>(src: ArrayString<N>) -> ArrayVec<u8, N> {
+    src.bytes().collect()
+}
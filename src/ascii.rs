@@ -0,0 +1,886 @@
+use core::fmt;
+
+use crate::{
+    checked::validate_bytes, safe_transmute_const, safe_transmute_mut, safe_transmute_ref,
+    safe_transmute_slice, try_transmute_slice, try_transmute_slice_mut, SafeTransmuteFrom,
+    SafeTransmuteMutFrom, SafeTransmuteRefFrom, TransmuteGuard, TryFromBytes, TryTransmuteMutFrom,
+    TryTransmuteRefFrom, TryTransmuteSliceFrom, ValidityError, Zeroable,
+};
+
+/// A `u8` that is always ASCII, which works on stable unlike `core::ascii::Char`
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct AsciiChar(u8);
+
+impl AsciiChar {
+    #[inline]
+    pub const fn new(byte: u8) -> Option<Self> {
+        if byte.is_ascii() {
+            Some(Self(byte))
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    /// `byte` must be ASCII
+    #[inline]
+    pub const unsafe fn new_unchecked(byte: u8) -> Self {
+        Self(byte)
+    }
+
+    #[inline]
+    pub const fn from_char(char: char) -> Option<Self> {
+        if char.is_ascii() {
+            Some(Self(char as u8))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub const fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn to_char(self) -> char {
+        self.0 as char
+    }
+}
+
+impl From<AsciiChar> for u8 {
+    #[inline]
+    fn from(value: AsciiChar) -> Self {
+        value.to_u8()
+    }
+}
+
+impl From<AsciiChar> for char {
+    #[inline]
+    fn from(value: AsciiChar) -> Self {
+        value.to_char()
+    }
+}
+
+impl TryFrom<u8> for AsciiChar {
+    type Error = ValidityError;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::new(value).ok_or(ValidityError { index: 0 })
+    }
+}
+
+impl fmt::Debug for AsciiChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_char(), f)
+    }
+}
+
+impl fmt::Display for AsciiChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_char(), f)
+    }
+}
+
+unsafe impl Zeroable for AsciiChar {}
+
+unsafe impl TryTransmuteSliceFrom<u8> for AsciiChar {
+    #[inline]
+    fn validate_slice(value: &[u8]) -> Result<(), ValidityError> {
+        validate_bytes(value, 0x80)
+    }
+}
+
+unsafe impl TryFromBytes for AsciiChar {
+    #[inline]
+    fn validate_bytes(bytes: &[u8]) -> Result<(), usize> {
+        if bytes[0].is_ascii() {
+            Ok(())
+        } else {
+            Err(0)
+        }
+    }
+}
+
+crate::impl_transmute_guard! {
+    unsafe AsciiChar => u8;
+    unsafe ?Sized [AsciiChar] => [u8];
+}
+
+unsafe impl<const N: usize> TransmuteGuard<[AsciiChar; N]> for [u8; N] {}
+unsafe impl<const N: usize> SafeTransmuteFrom<[AsciiChar; N]> for [u8; N] {
+    #[inline]
+    fn safe_transmute_from(value: [AsciiChar; N]) -> Self {
+        safe_transmute_const(value)
+    }
+}
+
+unsafe impl SafeTransmuteRefFrom<[AsciiChar]> for [u8] {
+    #[inline]
+    fn safe_transmute_ref_from(value: &[AsciiChar]) -> &Self {
+        safe_transmute_slice(value)
+    }
+}
+
+unsafe impl TransmuteGuard<[AsciiChar]> for str {}
+unsafe impl SafeTransmuteRefFrom<[AsciiChar]> for str {
+    #[inline]
+    fn safe_transmute_ref_from(value: &[AsciiChar]) -> &Self {
+        unsafe { core::str::from_utf8_unchecked(safe_transmute_slice(value)) }
+    }
+}
+
+/// A string slice that is always ASCII, made of [`AsciiChar`]s
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct AsciiStr([AsciiChar]);
+
+crate::impl_transmute_guard! {
+    unsafe ?Sized [AsciiChar] => AsciiStr;
+    unsafe ?Sized AsciiStr => [AsciiChar];
+    unsafe ?Sized AsciiStr => str;
+    unsafe ?Sized AsciiStr => [u8];
+}
+
+// Only a cast keeps the length of an unsized newtype, all other conversions go through the field
+unsafe impl SafeTransmuteRefFrom<[AsciiChar]> for AsciiStr {
+    #[inline]
+    fn safe_transmute_ref_from(value: &[AsciiChar]) -> &Self {
+        let from_ptr: *const [AsciiChar] = core::ptr::from_ref(value);
+        unsafe { &*(from_ptr as *const Self) }
+    }
+}
+
+unsafe impl SafeTransmuteRefFrom<AsciiStr> for [AsciiChar] {
+    #[inline]
+    fn safe_transmute_ref_from(value: &AsciiStr) -> &Self {
+        &value.0
+    }
+}
+
+unsafe impl SafeTransmuteRefFrom<AsciiStr> for str {
+    #[inline]
+    fn safe_transmute_ref_from(value: &AsciiStr) -> &Self {
+        safe_transmute_ref(&value.0)
+    }
+}
+
+unsafe impl SafeTransmuteRefFrom<AsciiStr> for [u8] {
+    #[inline]
+    fn safe_transmute_ref_from(value: &AsciiStr) -> &Self {
+        safe_transmute_slice(&value.0)
+    }
+}
+
+unsafe impl SafeTransmuteMutFrom<[AsciiChar]> for AsciiStr {
+    #[inline]
+    fn safe_transmute_mut_from(value: &mut [AsciiChar]) -> &mut Self {
+        let from_ptr: *mut [AsciiChar] = core::ptr::from_mut(value);
+        unsafe { &mut *(from_ptr as *mut Self) }
+    }
+}
+
+unsafe impl SafeTransmuteMutFrom<AsciiStr> for [AsciiChar] {
+    #[inline]
+    fn safe_transmute_mut_from(value: &mut AsciiStr) -> &mut Self {
+        &mut value.0
+    }
+}
+
+unsafe impl TryTransmuteRefFrom<[u8]> for AsciiStr {
+    #[inline]
+    fn try_transmute_ref_from(value: &[u8]) -> Result<&Self, ValidityError> {
+        Self::from_bytes(value)
+    }
+}
+
+unsafe impl TryTransmuteMutFrom<[u8]> for AsciiStr {
+    #[inline]
+    fn try_transmute_mut_from(value: &mut [u8]) -> Result<&mut Self, ValidityError> {
+        Self::from_bytes_mut(value)
+    }
+}
+
+unsafe impl TryTransmuteRefFrom<str> for AsciiStr {
+    #[inline]
+    fn try_transmute_ref_from(value: &str) -> Result<&Self, ValidityError> {
+        Self::try_from_str(value)
+    }
+}
+
+unsafe impl TryTransmuteMutFrom<str> for AsciiStr {
+    #[inline]
+    fn try_transmute_mut_from(value: &mut str) -> Result<&mut Self, ValidityError> {
+        Self::try_from_str_mut(value)
+    }
+}
+
+impl AsciiStr {
+    #[inline]
+    pub fn from_chars(chars: &[AsciiChar]) -> &Self {
+        safe_transmute_ref(chars)
+    }
+
+    #[inline]
+    pub fn from_chars_mut(chars: &mut [AsciiChar]) -> &mut Self {
+        safe_transmute_mut(chars)
+    }
+
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Self, ValidityError> {
+        try_transmute_slice(bytes).map(Self::from_chars)
+    }
+
+    #[inline]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, ValidityError> {
+        try_transmute_slice_mut(bytes).map(Self::from_chars_mut)
+    }
+
+    #[inline]
+    pub fn try_from_str(str: &str) -> Result<&Self, ValidityError> {
+        Self::from_bytes(str.as_bytes())
+    }
+
+    /// Same as [`AsciiStr::try_from_str`], except that it is slower and works in const context,
+    /// like [`ascii_str!`](crate::ascii_str)
+    #[inline]
+    pub const fn try_from_str_const(str: &str) -> Result<&Self, ValidityError> {
+        let bytes = str.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            if !bytes[index].is_ascii() {
+                return Err(ValidityError { index });
+            }
+            index += 1;
+        }
+        let bytes_ptr: *const [u8] = core::ptr::from_ref(bytes);
+        Ok(unsafe { &*(bytes_ptr as *const Self) })
+    }
+
+    /// Same as [`AsciiStr::try_from_str`], except that `str` stays mutable through the `AsciiStr`,
+    /// which can only write ASCII
+    #[inline]
+    pub fn try_from_str_mut(str: &mut str) -> Result<&mut Self, ValidityError> {
+        Self::from_bytes_mut(unsafe { str.as_bytes_mut() })
+    }
+
+    #[inline]
+    pub fn as_chars(&self) -> &[AsciiChar] {
+        &self.0
+    }
+
+    #[inline]
+    pub fn as_chars_mut(&mut self) -> &mut [AsciiChar] {
+        &mut self.0
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        safe_transmute_ref(self)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        safe_transmute_ref(self)
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: core::slice::SliceIndex<[AsciiChar]>,
+    {
+        self.0.get(index)
+    }
+
+    /// # Panics
+    /// Panics if `mid` is greater than the length
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (&Self, &Self) {
+        let (head, tail) = self.0.split_at(mid);
+        (Self::from_chars(head), Self::from_chars(tail))
+    }
+
+    #[inline]
+    pub fn split(&self, separator: AsciiChar) -> impl DoubleEndedIterator<Item = &Self> {
+        self.0
+            .split(move |char| *char == separator)
+            .map(Self::from_chars)
+    }
+
+    #[inline]
+    pub fn split_once(&self, separator: AsciiChar) -> Option<(&Self, &Self)> {
+        let index = self.0.iter().position(|char| *char == separator)?;
+        Some((
+            Self::from_chars(&self.0[..index]),
+            Self::from_chars(&self.0[index + 1..]),
+        ))
+    }
+
+    #[inline]
+    pub fn trim_ascii(&self) -> &Self {
+        let start = self.len() - self.as_bytes().trim_ascii_start().len();
+        let end = self.as_bytes().trim_ascii_end().len().max(start);
+        Self::from_chars(&self.0[start..end])
+    }
+}
+
+impl core::ops::Index<usize> for AsciiStr {
+    type Output = AsciiChar;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+macro_rules! impl_ascii_str_index {
+    ($($range:ty),* $(,)?) => {
+        $(
+            impl core::ops::Index<$range> for AsciiStr {
+                type Output = Self;
+
+                #[inline]
+                fn index(&self, index: $range) -> &Self::Output {
+                    Self::from_chars(&self.0[index])
+                }
+            }
+
+            impl core::ops::IndexMut<$range> for AsciiStr {
+                #[inline]
+                fn index_mut(&mut self, index: $range) -> &mut Self::Output {
+                    Self::from_chars_mut(&mut self.0[index])
+                }
+            }
+        )*
+    };
+}
+impl_ascii_str_index!(
+    core::ops::Range<usize>,
+    core::ops::RangeFrom<usize>,
+    core::ops::RangeTo<usize>,
+    core::ops::RangeInclusive<usize>,
+    core::ops::RangeToInclusive<usize>,
+    core::ops::RangeFull,
+);
+
+impl<'a> TryFrom<&'a [u8]> for &'a AsciiStr {
+    type Error = ValidityError;
+
+    #[inline]
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        AsciiStr::from_bytes(value)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a AsciiStr {
+    type Error = ValidityError;
+
+    #[inline]
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        AsciiStr::try_from_str(value)
+    }
+}
+
+impl AsRef<str> for AsciiStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for AsciiStr {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[AsciiChar]> for AsciiStr {
+    #[inline]
+    fn as_ref(&self) -> &[AsciiChar] {
+        &self.0
+    }
+}
+
+// Hashed as a `str`, so that maps with `AsciiStr` keys can be queried with a `str`
+impl core::hash::Hash for AsciiStr {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(self.as_str(), state);
+    }
+}
+
+impl core::borrow::Borrow<str> for AsciiStr {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+// Maps with borrowed keys need the reference itself to borrow as the inner type
+impl core::borrow::Borrow<str> for &AsciiStr {
+    #[inline]
+    fn borrow(&self) -> &str {
+        (*self).as_str()
+    }
+}
+
+impl PartialEq<str> for AsciiStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<AsciiStr> for str {
+    #[inline]
+    fn eq(&self, other: &AsciiStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl fmt::Debug for AsciiStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for AsciiStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// A `&'static AsciiStr` of a string literal or constant, which fails to compile if it is not
+/// ASCII, e.g. `ascii_str!("GET")`
+#[macro_export]
+macro_rules! ascii_str {
+    ($str:expr $(,)?) => {{
+        const ASCII_STR: &$crate::AsciiStr = match $crate::AsciiStr::try_from_str_const($str) {
+            ::core::result::Result::Ok(ascii) => ascii,
+            ::core::result::Result::Err(_) => ::core::panic!("`ascii_str!` needs an ASCII string"),
+        };
+        ASCII_STR
+    }};
+}
+
+macro_rules! ascii_subset {
+    ($($(#[$meta:meta])* $name:ident: $is:ident;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[cfg_attr(
+                feature = "zerocopy",
+                derive(
+                    zerocopy::IntoBytes,
+                    zerocopy::Immutable,
+                    zerocopy::KnownLayout,
+                    zerocopy::Unaligned
+                )
+            )]
+            #[repr(transparent)]
+            pub struct $name(u8);
+
+            impl $name {
+                #[inline]
+                pub const fn new(byte: u8) -> Option<Self> {
+                    if byte.$is() {
+                        Some(Self(byte))
+                    } else {
+                        None
+                    }
+                }
+
+                /// # Safety
+                #[doc = concat!("`byte` must satisfy `u8::", stringify!($is), "`")]
+                #[inline]
+                pub const unsafe fn new_unchecked(byte: u8) -> Self {
+                    Self(byte)
+                }
+
+                #[inline]
+                pub const fn to_u8(self) -> u8 {
+                    self.0
+                }
+
+                #[inline]
+                pub const fn to_char(self) -> char {
+                    self.0 as char
+                }
+
+                #[inline]
+                pub const fn to_ascii_char(self) -> AsciiChar {
+                    AsciiChar(self.0)
+                }
+
+                #[inline]
+                pub fn slice_as_ascii_str(slice: &[Self]) -> &AsciiStr {
+                    safe_transmute_ref(slice)
+                }
+            }
+
+            impl From<$name> for u8 {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    value.to_u8()
+                }
+            }
+
+            impl From<$name> for AsciiChar {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    value.to_ascii_char()
+                }
+            }
+
+            impl TryFrom<u8> for $name {
+                type Error = ValidityError;
+
+                #[inline]
+                fn try_from(value: u8) -> Result<Self, Self::Error> {
+                    Self::new(value).ok_or(ValidityError { index: 0 })
+                }
+            }
+
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&self.to_char(), f)
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.to_char(), f)
+                }
+            }
+
+            unsafe impl TryTransmuteSliceFrom<u8> for $name {
+                #[inline]
+                fn validate_slice(value: &[u8]) -> Result<(), ValidityError> {
+                    match value.iter().position(|byte| !byte.$is()) {
+                        Some(index) => Err(ValidityError { index }),
+                        None => Ok(()),
+                    }
+                }
+            }
+
+            unsafe impl TryFromBytes for $name {
+                #[inline]
+                fn validate_bytes(bytes: &[u8]) -> Result<(), usize> {
+                    if bytes[0].$is() {
+                        Ok(())
+                    } else {
+                        Err(0)
+                    }
+                }
+            }
+
+            crate::impl_transmute_guard! {
+                unsafe $name => u8;
+                unsafe $name => AsciiChar;
+                unsafe ?Sized [$name] => [u8];
+                unsafe ?Sized [$name] => [AsciiChar];
+                unsafe ?Sized [$name] => AsciiStr;
+                unsafe ?Sized [$name] => str;
+            }
+
+            unsafe impl SafeTransmuteRefFrom<[$name]> for [u8] {
+                #[inline]
+                fn safe_transmute_ref_from(value: &[$name]) -> &Self {
+                    safe_transmute_slice(value)
+                }
+            }
+
+            unsafe impl SafeTransmuteRefFrom<[$name]> for [AsciiChar] {
+                #[inline]
+                fn safe_transmute_ref_from(value: &[$name]) -> &Self {
+                    safe_transmute_slice(value)
+                }
+            }
+
+            unsafe impl SafeTransmuteRefFrom<[$name]> for AsciiStr {
+                #[inline]
+                fn safe_transmute_ref_from(value: &[$name]) -> &Self {
+                    AsciiStr::from_chars(safe_transmute_slice(value))
+                }
+            }
+
+            unsafe impl SafeTransmuteRefFrom<[$name]> for str {
+                #[inline]
+                fn safe_transmute_ref_from(value: &[$name]) -> &Self {
+                    AsciiStr::from_chars(safe_transmute_slice(value)).as_str()
+                }
+            }
+        )*
+    };
+}
+ascii_subset! {
+    /// An ASCII decimal digit `0-9`
+    AsciiDigit: is_ascii_digit;
+    /// An ASCII hexadecimal digit `0-9`, `a-f` or `A-F`
+    HexDigit: is_ascii_hexdigit;
+    /// An ASCII letter `a-z` or `A-Z`
+    AsciiAlpha: is_ascii_alphabetic;
+}
+
+impl AsciiDigit {
+    #[inline]
+    pub const fn to_digit(self) -> u8 {
+        self.0 - b'0'
+    }
+}
+
+impl HexDigit {
+    #[inline]
+    pub const fn to_digit(self) -> u8 {
+        match self.0 {
+            b'0'..=b'9' => self.0 - b'0',
+            b'a'..=b'f' => self.0 - b'a' + 10,
+            _ => self.0 - b'A' + 10,
+        }
+    }
+}
+
+#[cfg(feature = "ascii")]
+mod core_ascii {
+    use core::ascii::Char;
+
+    use super::AsciiChar;
+    use crate::{
+        safe_transmute_const, safe_transmute_slice, try_transmute_slice, try_transmute_slice_mut,
+        SafeTransmuteFrom, SafeTransmuteRefFrom, TransmuteGuard, ValidityError,
+    };
+
+    crate::impl_transmute_guard! {
+        unsafe bidirectional AsciiChar <=> Char;
+        unsafe Char => u8;
+        unsafe ?Sized [Char] => [u8];
+        unsafe ?Sized [Char] => str;
+    }
+
+    unsafe impl<const N: usize> TransmuteGuard<[Char; N]> for [u8; N] {}
+    unsafe impl<const N: usize> SafeTransmuteFrom<[Char; N]> for [u8; N] {
+        #[inline]
+        fn safe_transmute_from(value: [Char; N]) -> Self {
+            safe_transmute_const(value)
+        }
+    }
+
+    unsafe impl SafeTransmuteRefFrom<[Char]> for [u8] {
+        #[inline]
+        fn safe_transmute_ref_from(value: &[Char]) -> &Self {
+            safe_transmute_slice(value)
+        }
+    }
+
+    unsafe impl SafeTransmuteRefFrom<[Char]> for str {
+        #[inline]
+        fn safe_transmute_ref_from(value: &[Char]) -> &Self {
+            value.as_str()
+        }
+    }
+
+    /// Views `str` as `ascii::Char`s after checking that it is ASCII
+    #[inline]
+    pub fn try_transmute_str_ascii_chars(str: &str) -> Result<&[Char], ValidityError> {
+        try_transmute_slice(str.as_bytes())
+    }
+
+    /// Same as [`try_transmute_str_ascii_chars`], except that `str` stays mutable through the
+    /// `ascii::Char`s, which can only write ASCII
+    #[inline]
+    pub fn try_transmute_str_ascii_chars_mut(str: &mut str) -> Result<&mut [Char], ValidityError> {
+        try_transmute_slice_mut(unsafe { str.as_bytes_mut() })
+    }
+}
+#[cfg(feature = "ascii")]
+pub use core_ascii::{try_transmute_str_ascii_chars, try_transmute_str_ascii_chars_mut};
+
+/// A string slice whose `Eq`, `Hash` and `Ord` ignore ASCII case, e.g. for header names
+#[repr(transparent)]
+pub struct UniCaseAscii<S>(S)
+where
+    S: ?Sized;
+
+macro_rules! impl_uni_case_ascii {
+    ($($S:ty),* $(,)?) => {
+        $(
+            crate::impl_transmute_guard! {
+                unsafe ?Sized $S => UniCaseAscii<$S>;
+                unsafe ?Sized UniCaseAscii<$S> => $S;
+            }
+
+            unsafe impl SafeTransmuteRefFrom<$S> for UniCaseAscii<$S> {
+                #[inline]
+                fn safe_transmute_ref_from(value: &$S) -> &Self {
+                    let from_ptr: *const $S = core::ptr::from_ref(value);
+                    unsafe { &*(from_ptr as *const Self) }
+                }
+            }
+
+            unsafe impl SafeTransmuteRefFrom<UniCaseAscii<$S>> for $S {
+                #[inline]
+                fn safe_transmute_ref_from(value: &UniCaseAscii<$S>) -> &Self {
+                    &value.0
+                }
+            }
+
+            unsafe impl SafeTransmuteMutFrom<$S> for UniCaseAscii<$S> {
+                #[inline]
+                fn safe_transmute_mut_from(value: &mut $S) -> &mut Self {
+                    let from_ptr: *mut $S = core::ptr::from_mut(value);
+                    unsafe { &mut *(from_ptr as *mut Self) }
+                }
+            }
+
+            unsafe impl SafeTransmuteMutFrom<UniCaseAscii<$S>> for $S {
+                #[inline]
+                fn safe_transmute_mut_from(value: &mut UniCaseAscii<$S>) -> &mut Self {
+                    &mut value.0
+                }
+            }
+
+            impl UniCaseAscii<$S> {
+                #[inline]
+                pub fn new(value: &$S) -> &Self {
+                    safe_transmute_ref(value)
+                }
+
+                #[inline]
+                pub fn get(&self) -> &$S {
+                    &self.0
+                }
+            }
+
+            impl<'a> From<&'a $S> for &'a UniCaseAscii<$S> {
+                #[inline]
+                fn from(value: &'a $S) -> Self {
+                    UniCaseAscii::<$S>::new(value)
+                }
+            }
+
+            impl PartialEq for UniCaseAscii<$S> {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.0.as_bytes().eq_ignore_ascii_case(other.0.as_bytes())
+                }
+            }
+
+            impl Eq for UniCaseAscii<$S> {}
+
+            impl PartialOrd for UniCaseAscii<$S> {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for UniCaseAscii<$S> {
+                #[inline]
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    let lower = |byte: &u8| byte.to_ascii_lowercase();
+                    let other = other.0.as_bytes().iter().map(lower);
+                    self.0.as_bytes().iter().map(lower).cmp(other)
+                }
+            }
+
+            impl core::hash::Hash for UniCaseAscii<$S> {
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    state.write_usize(self.0.len());
+                    for byte in self.0.as_bytes() {
+                        state.write_u8(byte.to_ascii_lowercase());
+                    }
+                }
+            }
+
+            impl fmt::Debug for UniCaseAscii<$S> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&self.0, f)
+                }
+            }
+
+            impl fmt::Display for UniCaseAscii<$S> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+impl_uni_case_ascii!(str, AsciiStr);
+
+/// Implements `fmt::Write` by writing into a buffer of `AsciiChar`s, e.g.
+/// `write!(writer, "{value}")`.
+///
+/// Writing fails without writing anything if the string is not ASCII or does not fit.
+#[derive(Debug)]
+pub struct AsciiWriter<'a> {
+    buf: &'a mut [AsciiChar],
+    len: usize,
+}
+
+impl<'a> AsciiWriter<'a> {
+    #[inline]
+    pub const fn new(buf: &'a mut [AsciiChar]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Number of chars written so far
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn as_ascii_str(&self) -> &AsciiStr {
+        AsciiStr::from_chars(&self.buf[..self.len])
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        safe_transmute_ref(self.as_ascii_str())
+    }
+
+    #[inline]
+    pub fn into_str(self) -> &'a str {
+        safe_transmute_ref::<[AsciiChar], str>(&self.buf[..self.len])
+    }
+}
+
+impl fmt::Write for AsciiWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let chars = AsciiStr::try_from_str(s).map_err(|_| fmt::Error)?;
+        self.buf
+            .get_mut(self.len..self.len + chars.len())
+            .ok_or(fmt::Error)?
+            .copy_from_slice(chars.as_chars());
+        self.len += chars.len();
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+/// Fails compilation if `Src` can't be safely transmuted into `Dst`, e.g.
+/// `assert_transmutable!(bool => u8);`.
+///
+/// `?Sized` only checks the `TransmuteGuard` impl, otherwise the sizes are checked as well.
+#[macro_export]
+macro_rules! assert_transmutable {
+    (?Sized $Src:ty => $Dst:ty $(,)?) => {
+        const _: () = {
+            const fn assert_transmutable<Src, Dst>()
+            where
+                Src: ?Sized,
+                Dst: $crate::TransmuteGuard<Src> + ?Sized,
+            {
+            }
+            assert_transmutable::<$Src, $Dst>();
+        };
+    };
+    ($Src:ty => $Dst:ty $(,)?) => {
+        $crate::assert_transmutable!(?Sized $Src => $Dst);
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$Src>() == ::core::mem::size_of::<$Dst>(),
+            ::core::concat!(
+                "`",
+                ::core::stringify!($Src),
+                "` and `",
+                ::core::stringify!($Dst),
+                "` have different sizes",
+            ),
+        );
+    };
+}
+
+/// Fails compilation if `A` and `B` have different sizes or alignments, e.g.
+/// `assert_same_layout!(u32, [u8; 4]);` fails because of the alignment.
+#[macro_export]
+macro_rules! assert_same_layout {
+    ($A:ty, $B:ty $(,)?) => {
+        const _: () = {
+            ::core::assert!(
+                ::core::mem::size_of::<$A>() == ::core::mem::size_of::<$B>(),
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($A),
+                    "` and `",
+                    ::core::stringify!($B),
+                    "` have different sizes",
+                ),
+            );
+            ::core::assert!(
+                ::core::mem::align_of::<$A>() == ::core::mem::align_of::<$B>(),
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($A),
+                    "` and `",
+                    ::core::stringify!($B),
+                    "` have different alignments",
+                ),
+            );
+        };
+    };
+}
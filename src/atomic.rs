@@ -0,0 +1,109 @@
+use core::mem::{align_of, size_of};
+
+/// Atomic types that can be viewed as their value type through mutable references, which is
+/// race-free because a mutable reference is unique
+///
+/// There are no transmute guards between them, because a shared reference to an atomic can be
+/// written to and a shared reference to its value type cannot.
+///
+/// # Safety
+/// Only implement this trait if `Self` has the same size and bit validity as `Value`
+pub unsafe trait Atomic: Sized {
+    type Value: Copy;
+}
+
+/// Fails to compile if `A` is more aligned than its value type
+#[inline]
+pub fn atomic_from_mut<A>(value: &mut A::Value) -> &mut A
+where
+    A: Atomic,
+{
+    const {
+        assert!(size_of::<A>() == size_of::<A::Value>());
+        assert!(align_of::<A::Value>() >= align_of::<A>());
+    }
+    unsafe { &mut *core::ptr::from_mut(value).cast() }
+}
+
+#[inline]
+pub fn atomic_get_mut<A>(atomic: &mut A) -> &mut A::Value
+where
+    A: Atomic,
+{
+    const {
+        assert!(size_of::<A>() == size_of::<A::Value>());
+    }
+    unsafe { &mut *core::ptr::from_mut(atomic).cast() }
+}
+
+/// Fails to compile if `A` is more aligned than its value type
+#[inline]
+pub fn atomic_from_mut_slice<A>(values: &mut [A::Value]) -> &mut [A]
+where
+    A: Atomic,
+{
+    const {
+        assert!(size_of::<A>() == size_of::<A::Value>());
+        assert!(align_of::<A::Value>() >= align_of::<A>());
+    }
+    unsafe { &mut *(core::ptr::from_mut(values) as *mut [A]) }
+}
+
+#[inline]
+pub fn atomic_get_mut_slice<A>(atomics: &mut [A]) -> &mut [A::Value]
+where
+    A: Atomic,
+{
+    const {
+        assert!(size_of::<A>() == size_of::<A::Value>());
+    }
+    unsafe { &mut *(core::ptr::from_mut(atomics) as *mut [A::Value]) }
+}
+
+macro_rules! impl_atomic {
+    ($($(#[$meta:meta])* $atomic:ty => $value:ty;)*) => {
+        $($(#[$meta])* unsafe impl Atomic for $atomic {
+            type Value = $value;
+        })*
+    };
+}
+
+impl_atomic! {
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicBool => bool;
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicU8 => u8;
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicI8 => i8;
+    #[cfg(target_has_atomic = "16")]
+    core::sync::atomic::AtomicU16 => u16;
+    #[cfg(target_has_atomic = "16")]
+    core::sync::atomic::AtomicI16 => i16;
+    #[cfg(target_has_atomic = "32")]
+    core::sync::atomic::AtomicU32 => u32;
+    #[cfg(target_has_atomic = "32")]
+    core::sync::atomic::AtomicI32 => i32;
+    #[cfg(target_has_atomic = "64")]
+    core::sync::atomic::AtomicU64 => u64;
+    #[cfg(target_has_atomic = "64")]
+    core::sync::atomic::AtomicI64 => i64;
+    #[cfg(target_has_atomic = "ptr")]
+    core::sync::atomic::AtomicUsize => usize;
+    #[cfg(target_has_atomic = "ptr")]
+    core::sync::atomic::AtomicIsize => isize;
+}
+
+#[cfg(feature = "portable-atomic")]
+impl_atomic! {
+    portable_atomic::AtomicBool => bool;
+    portable_atomic::AtomicU8 => u8;
+    portable_atomic::AtomicI8 => i8;
+    portable_atomic::AtomicU16 => u16;
+    portable_atomic::AtomicI16 => i16;
+    portable_atomic::AtomicU32 => u32;
+    portable_atomic::AtomicI32 => i32;
+    portable_atomic::AtomicU64 => u64;
+    portable_atomic::AtomicI64 => i64;
+    portable_atomic::AtomicUsize => usize;
+    portable_atomic::AtomicIsize => isize;
+}
@@ -0,0 +1,126 @@
+#[cfg(feature = "audit")]
+mod checks {
+    use core::any::type_name;
+    use core::marker::PhantomData;
+    use core::mem::{align_of, align_of_val, size_of, size_of_val};
+
+    use crate::{bytes_of, Pod, TryFromBytes};
+
+    /// Checks in debug builds that `dst` has the size of the `Src` it was transmuted from and is
+    /// aligned for its type
+    #[inline]
+    #[track_caller]
+    pub(crate) fn audit_ref<Src, Dst>(src_size: usize, dst: &Dst)
+    where
+        Src: ?Sized,
+        Dst: ?Sized,
+    {
+        debug_assert!(
+            src_size == size_of_val(dst),
+            "audit: `{}` and `{}` have different sizes",
+            type_name::<Src>(),
+            type_name::<Dst>(),
+        );
+        debug_assert!(
+            core::ptr::from_ref(dst)
+                .cast::<u8>()
+                .addr()
+                .is_multiple_of(align_of_val(dst)),
+            "audit: `{}` transmuted to a misaligned `{}`",
+            type_name::<Src>(),
+            type_name::<Dst>(),
+        );
+    }
+
+    /// Checks in debug builds that `Src` and `Dst` have the same size and that `Dst` is not more
+    /// aligned, which is all that can be checked in const fns
+    #[inline]
+    #[track_caller]
+    pub(crate) const fn audit_layout<Src, Dst>() {
+        debug_assert!(
+            size_of::<Src>() == size_of::<Dst>(),
+            "audit: the source and destination have different sizes",
+        );
+        debug_assert!(
+            align_of::<Src>() >= align_of::<Dst>(),
+            "audit: the destination is more aligned than the source",
+        );
+    }
+
+    /// Picks [`AuditBytes`] over [`AuditFallback`] through autoref if the bytes of `Src` can be
+    /// read and `Dst` can validate them
+    #[doc(hidden)]
+    pub struct Audit<Src, Dst>(pub PhantomData<(Src, Dst)>);
+
+    #[doc(hidden)]
+    pub trait AuditBytes<Src> {
+        fn audit(&self, src: &Src);
+    }
+
+    impl<Src, Dst> AuditBytes<Src> for Audit<Src, Dst>
+    where
+        Src: Pod,
+        Dst: TryFromBytes,
+    {
+        #[inline]
+        #[track_caller]
+        fn audit(&self, src: &Src) {
+            debug_assert!(
+                Dst::validate_bytes(bytes_of(src)).is_ok(),
+                "audit: the `{}` is not a valid `{}`",
+                type_name::<Src>(),
+                type_name::<Dst>(),
+            );
+        }
+    }
+
+    #[doc(hidden)]
+    pub trait AuditFallback<Src> {
+        #[inline]
+        fn audit(&self, _src: &Src) {}
+    }
+
+    impl<Src, Dst> AuditFallback<Src> for &Audit<Src, Dst> {}
+}
+
+#[cfg(feature = "audit")]
+pub(crate) use checks::{audit_layout, audit_ref};
+#[cfg(feature = "audit")]
+pub use checks::{Audit, AuditBytes, AuditFallback};
+
+#[cfg(feature = "audit")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __audit_transmute {
+    { $From:ty => $To:ty, $value:expr } => {{
+        #[allow(unused_imports)]
+        use $crate::__private::audit::{AuditBytes as _, AuditFallback as _};
+        (&$crate::__private::audit::Audit::<$From, $To>(::core::marker::PhantomData)).audit($value);
+    }};
+}
+
+#[cfg(not(feature = "audit"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __audit_transmute {
+    { $($input:tt)* } => {};
+}
+
+#[cfg(feature = "audit")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __audit_guard {
+    { $From:ty => $To:ty } => {
+        #[inline]
+        fn audit(value: &$From) {
+            $crate::__audit_transmute! { $From => $To, value }
+        }
+    };
+}
+
+#[cfg(not(feature = "audit"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __audit_guard {
+    { $($input:tt)* } => {};
+}
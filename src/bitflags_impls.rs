@@ -0,0 +1,52 @@
+/// Implements the transmute guards in both directions between bitflags types and their bits,
+/// e.g. `bitflags_guards! { unsafe Status; }`.
+///
+/// `unsafe` asserts that each type is declared with `#[repr(transparent)]` inside `bitflags!`.
+/// Every value of the bits is a valid flags value, because unknown bits are retained. The sizes
+/// and alignments are checked at compile time.
+///
+/// Requires the `bitflags` feature.
+#[cfg(feature = "bitflags")]
+#[macro_export]
+macro_rules! bitflags_guards {
+    {} => {};
+    { $(#[$attr:meta])* unsafe $($name:ident)::+ $(; $($rest:tt)*)? } => {
+        $(#[$attr])*
+        const _: () = {
+            type Bits = <$($name)::+ as $crate::__private::bitflags::Flags>::Bits;
+            ::core::assert!(
+                ::core::mem::size_of::<$($name)::+>() == ::core::mem::size_of::<Bits>()
+                    && ::core::mem::align_of::<$($name)::+>() == ::core::mem::align_of::<Bits>(),
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($($name)::+),
+                    "` does not have the layout of its bits",
+                ),
+            );
+        };
+        $crate::impl_transmute_guard! {
+            $(#[$attr])*
+            unsafe bidirectional $($name)::+
+                <=> <$($name)::+ as $crate::__private::bitflags::Flags>::Bits
+        }
+        $crate::bitflags_guards! { $($($rest)*)? }
+    };
+}
+
+/// Implements the transmute guards in both directions between bitflags types and their bits,
+/// e.g. `bitflags_guards! { unsafe Status; }`.
+///
+/// `unsafe` asserts that each type is declared with `#[repr(transparent)]` inside `bitflags!`.
+/// Every value of the bits is a valid flags value, because unknown bits are retained. The sizes
+/// and alignments are checked at compile time.
+///
+/// Requires the `bitflags` feature.
+#[cfg(not(feature = "bitflags"))]
+#[macro_export]
+macro_rules! bitflags_guards {
+    { $($input:tt)* } => {
+        ::core::compile_error!(
+            "`bitflags_guards!` requires the `bitflags` feature of `transmute_guard`"
+        );
+    };
+}
@@ -0,0 +1,152 @@
+/// Declares newtypes of primitive integers that only hold values in an inclusive range, e.g.
+/// `bounded_int! { pub struct Percent(u8): 0..=100; }`.
+///
+/// The newtypes transmute one way to the integer, and `slice_as_ints` views slices of them as
+/// slices of the integer. Integers and their slices go the other way through `new`, `TryFrom`,
+/// `TryTransmuteSliceFrom` and `TryFromBytes`, which check the range. `checked_*` and
+/// `saturating_*` arithmetic stays in the range. `Copy`, `Clone`, `Debug`, `PartialEq`, `Eq`,
+/// `PartialOrd`, `Ord` and `Hash` are always derived.
+#[macro_export]
+macro_rules! bounded_int {
+    {} => {};
+    {
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($int:ident): $min:literal..=$max:literal;
+        $($rest:tt)*
+    } => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        $vis struct $name($int);
+
+        const _: () = ::core::assert!(
+            $min <= $max,
+            ::core::concat!("the range of `", ::core::stringify!($name), "` is empty"),
+        );
+
+        impl $name {
+            $vis const MIN: Self = Self($min);
+            $vis const MAX: Self = Self($max);
+
+            #[inline]
+            $vis const fn contains(value: $int) -> bool {
+                $min <= value && value <= $max
+            }
+
+            #[inline]
+            $vis const fn new(value: $int) -> ::core::option::Option<Self> {
+                if Self::contains(value) {
+                    ::core::option::Option::Some(Self(value))
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+
+            /// # Safety
+            #[doc = ::core::concat!(
+                "`value` must be in `", ::core::stringify!($min), "..=", ::core::stringify!($max),
+                "`",
+            )]
+            #[inline]
+            $vis const unsafe fn new_unchecked(value: $int) -> Self {
+                Self(value)
+            }
+
+            /// The closest value in the range
+            #[inline]
+            $vis const fn saturating_new(value: $int) -> Self {
+                if value < $min {
+                    Self::MIN
+                } else if value > $max {
+                    Self::MAX
+                } else {
+                    Self(value)
+                }
+            }
+
+            #[inline]
+            $vis const fn get(self) -> $int {
+                self.0
+            }
+
+            #[inline]
+            $vis const fn checked_add(self, rhs: $int) -> ::core::option::Option<Self> {
+                match self.0.checked_add(rhs) {
+                    ::core::option::Option::Some(value) => Self::new(value),
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            $vis const fn checked_sub(self, rhs: $int) -> ::core::option::Option<Self> {
+                match self.0.checked_sub(rhs) {
+                    ::core::option::Option::Some(value) => Self::new(value),
+                    ::core::option::Option::None => ::core::option::Option::None,
+                }
+            }
+
+            #[inline]
+            $vis const fn saturating_add(self, rhs: $int) -> Self {
+                Self::saturating_new(self.0.saturating_add(rhs))
+            }
+
+            #[inline]
+            $vis const fn saturating_sub(self, rhs: $int) -> Self {
+                Self::saturating_new(self.0.saturating_sub(rhs))
+            }
+
+            #[inline]
+            $vis const fn slice_as_ints(slice: &[Self]) -> &[$int] {
+                $crate::safe_transmute_slice(slice)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $int {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::convert::TryFrom<$int> for $name {
+            type Error = $crate::ValidityError;
+
+            #[inline]
+            fn try_from(value: $int) -> ::core::result::Result<Self, Self::Error> {
+                Self::new(value).ok_or($crate::ValidityError { index: 0 })
+            }
+        }
+
+        unsafe impl $crate::TryTransmuteSliceFrom<$int> for $name {
+            #[inline]
+            fn validate_slice(
+                value: &[$int],
+            ) -> ::core::result::Result<(), $crate::ValidityError> {
+                match value.iter().position(|value| !Self::contains(*value)) {
+                    ::core::option::Option::Some(index) => {
+                        ::core::result::Result::Err($crate::ValidityError { index })
+                    }
+                    ::core::option::Option::None => ::core::result::Result::Ok(()),
+                }
+            }
+        }
+
+        unsafe impl $crate::TryFromBytes for $name {
+            #[inline]
+            fn validate_bytes(bytes: &[u8]) -> ::core::result::Result<(), usize> {
+                match ::core::convert::TryInto::try_into(bytes) {
+                    ::core::result::Result::Ok(bytes)
+                        if Self::contains(<$int>::from_ne_bytes(bytes)) =>
+                    {
+                        ::core::result::Result::Ok(())
+                    }
+                    _ => ::core::result::Result::Err(0),
+                }
+            }
+        }
+
+        $crate::impl_transmute_guard! { unsafe $name => $int }
+
+        $crate::bounded_int! { $($rest)* }
+    };
+}
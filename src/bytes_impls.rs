@@ -0,0 +1,46 @@
+use alloc::vec::Vec;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::pod::bytes_of_slice;
+use crate::{try_cast_slice, try_cast_slice_mut, CastError, Pod};
+
+/// Views the bytes of `bytes` as `T`s, failing if they are not aligned for `T` or the length is not
+/// a multiple of the size of `T`
+#[inline]
+pub fn try_cast_bytes<T>(bytes: &Bytes) -> Result<&[T], CastError>
+where
+    T: Pod,
+{
+    try_cast_slice(bytes)
+}
+
+/// Same as [`try_cast_bytes`], except that the bytes can be changed
+#[inline]
+pub fn try_cast_bytes_mut<T>(bytes: &mut BytesMut) -> Result<&mut [T], CastError>
+where
+    T: Pod,
+{
+    try_cast_slice_mut(bytes)
+}
+
+struct TypedOwner<T>(Vec<T>);
+
+impl<T> AsRef<[u8]> for TypedOwner<T>
+where
+    T: Pod,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        bytes_of_slice(&self.0)
+    }
+}
+
+/// Turns `values` into `Bytes` of their bytes without copying, keeping the allocation of `values`
+#[inline]
+pub fn freeze_typed<T>(values: Vec<T>) -> Bytes
+where
+    T: Pod + Send,
+{
+    Bytes::from_owner(TypedOwner(values))
+}
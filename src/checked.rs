@@ -0,0 +1,404 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::mem::{align_of, size_of};
+
+#[cfg(feature = "alloc")]
+use crate::BufferError;
+use crate::{scan::find_masked_byte, AlignmentError, CastError, LengthError, Pod, ValidityError};
+
+/// # Safety
+/// Only implement this trait if transmuting from `Self` to `T` is always safe and transmuting from
+/// `T` to `Self` is safe for every element that `validate_slice` accepts
+pub unsafe trait TryTransmuteSliceFrom<T>: Sized {
+    fn validate_slice(value: &[T]) -> Result<(), ValidityError>;
+
+    #[inline]
+    fn try_transmute_slice_from(value: &[T]) -> Result<&[Self], ValidityError> {
+        const {
+            assert!(core::mem::size_of::<T>() == core::mem::size_of::<Self>());
+            assert!(core::mem::align_of::<T>() >= core::mem::align_of::<Self>());
+        }
+        Self::validate_slice(value)?;
+        Ok(unsafe { core::slice::from_raw_parts(value.as_ptr().cast(), value.len()) })
+    }
+
+    #[inline]
+    fn try_transmute_slice_mut_from(value: &mut [T]) -> Result<&mut [Self], ValidityError> {
+        const {
+            assert!(core::mem::size_of::<T>() == core::mem::size_of::<Self>());
+            assert!(core::mem::align_of::<T>() >= core::mem::align_of::<Self>());
+        }
+        Self::validate_slice(value)?;
+        Ok(unsafe { core::slice::from_raw_parts_mut(value.as_mut_ptr().cast(), value.len()) })
+    }
+}
+
+#[inline]
+pub(crate) fn validate_bytes(value: &[u8], invalid_bits: u8) -> Result<(), ValidityError> {
+    match find_masked_byte(value, invalid_bits) {
+        Some(index) => Err(ValidityError { index }),
+        None => Ok(()),
+    }
+}
+
+unsafe impl TryTransmuteSliceFrom<u8> for bool {
+    #[inline]
+    fn validate_slice(value: &[u8]) -> Result<(), ValidityError> {
+        validate_bytes(value, 0xfe)
+    }
+}
+
+#[cfg(feature = "ascii")]
+unsafe impl TryTransmuteSliceFrom<u8> for core::ascii::Char {
+    #[inline]
+    fn validate_slice(value: &[u8]) -> Result<(), ValidityError> {
+        validate_bytes(value, 0x80)
+    }
+}
+
+#[inline]
+pub fn try_transmute_slice<Src, Dst>(src: &[Src]) -> Result<&[Dst], ValidityError>
+where
+    Dst: TryTransmuteSliceFrom<Src>,
+{
+    Dst::try_transmute_slice_from(src)
+}
+
+#[inline]
+pub fn try_transmute_slice_mut<Src, Dst>(src: &mut [Src]) -> Result<&mut [Dst], ValidityError>
+where
+    Dst: TryTransmuteSliceFrom<Src>,
+{
+    Dst::try_transmute_slice_mut_from(src)
+}
+
+/// The fallible counterpart of [`SafeTransmuteRefFrom`](crate::SafeTransmuteRefFrom), which also
+/// covers unsized views like `str` that [`TryTransmuteSliceFrom`] cannot express.
+///
+/// # Safety
+/// Only implement this trait if `try_transmute_ref_from` only succeeds with a valid `Self` over the
+/// bytes of `value`
+pub unsafe trait TryTransmuteRefFrom<T>
+where
+    T: ?Sized,
+{
+    fn try_transmute_ref_from(value: &T) -> Result<&Self, ValidityError>;
+}
+
+/// # Safety
+/// Only implement this trait if `try_transmute_mut_from` only succeeds with a valid `Self` over the
+/// bytes of `value`, and every value that can be written through the `Self` is a valid `T`
+pub unsafe trait TryTransmuteMutFrom<T>: TryTransmuteRefFrom<T>
+where
+    T: ?Sized,
+{
+    fn try_transmute_mut_from(value: &mut T) -> Result<&mut Self, ValidityError>;
+}
+
+unsafe impl<T, U> TryTransmuteRefFrom<[T]> for [U]
+where
+    U: TryTransmuteSliceFrom<T>,
+{
+    #[inline]
+    fn try_transmute_ref_from(value: &[T]) -> Result<&Self, ValidityError> {
+        U::try_transmute_slice_from(value)
+    }
+}
+
+unsafe impl<T, U> TryTransmuteMutFrom<[T]> for [U]
+where
+    U: TryTransmuteSliceFrom<T>,
+{
+    #[inline]
+    fn try_transmute_mut_from(value: &mut [T]) -> Result<&mut Self, ValidityError> {
+        U::try_transmute_slice_mut_from(value)
+    }
+}
+
+/// Checks that `bytes` is UTF-8, which only runs the UTF-8 validation from the first byte that is
+/// not ASCII
+#[inline]
+fn validate_utf8(bytes: &[u8]) -> Result<(), ValidityError> {
+    let Some(ascii) = find_masked_byte(bytes, 0x80) else {
+        return Ok(());
+    };
+    match core::str::from_utf8(&bytes[ascii..]) {
+        Ok(_) => Ok(()),
+        Err(error) => Err(ValidityError {
+            index: ascii + error.valid_up_to(),
+        }),
+    }
+}
+
+unsafe impl TryTransmuteRefFrom<[u8]> for str {
+    #[inline]
+    fn try_transmute_ref_from(value: &[u8]) -> Result<&Self, ValidityError> {
+        validate_utf8(value)?;
+        Ok(unsafe { core::str::from_utf8_unchecked(value) })
+    }
+}
+
+unsafe impl TryTransmuteMutFrom<[u8]> for str {
+    #[inline]
+    fn try_transmute_mut_from(value: &mut [u8]) -> Result<&mut Self, ValidityError> {
+        validate_utf8(value)?;
+        Ok(unsafe { core::str::from_utf8_unchecked_mut(value) })
+    }
+}
+
+/// Views `src` as a `Dst` after checking its bit pattern, e.g. `try_transmute_ref::<[u8], str>`,
+/// where only the bytes from the first one that is not ASCII are validated as UTF-8
+#[inline]
+pub fn try_transmute_ref<Src, Dst>(src: &Src) -> Result<&Dst, ValidityError>
+where
+    Dst: TryTransmuteRefFrom<Src> + ?Sized,
+    Src: ?Sized,
+{
+    Dst::try_transmute_ref_from(src)
+}
+
+#[inline]
+pub fn try_transmute_mut<Src, Dst>(src: &mut Src) -> Result<&mut Dst, ValidityError>
+where
+    Dst: TryTransmuteMutFrom<Src> + ?Sized,
+    Src: ?Sized,
+{
+    Dst::try_transmute_mut_from(src)
+}
+
+/// # Safety
+/// Only implement this trait if every byte slice with the size of `Self` that `validate_bytes`
+/// accepts is a valid `Self`
+#[diagnostic::on_unimplemented(
+    message = "the bytes of `{Self}` cannot be validated",
+    label = "no `TryFromBytes` for `{Self}`",
+    note = "derive `TryFromBytes` or write an `unsafe impl` that checks every invalid bit pattern"
+)]
+pub unsafe trait TryFromBytes: Sized {
+    /// Checks whether `bytes`, which always has the size of `Self`, is a valid `Self`, or returns
+    /// the offset of the first byte that makes it invalid
+    fn validate_bytes(bytes: &[u8]) -> Result<(), usize>;
+}
+
+unsafe impl<T> TryFromBytes for T
+where
+    T: Pod,
+{
+    #[inline]
+    fn validate_bytes(_: &[u8]) -> Result<(), usize> {
+        Ok(())
+    }
+}
+
+unsafe impl TryFromBytes for bool {
+    #[inline]
+    fn validate_bytes(bytes: &[u8]) -> Result<(), usize> {
+        if bytes[0] < 2 {
+            Ok(())
+        } else {
+            Err(0)
+        }
+    }
+}
+
+unsafe impl TryFromBytes for char {
+    #[inline]
+    fn validate_bytes(bytes: &[u8]) -> Result<(), usize> {
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        char::from_u32(u32::from_ne_bytes(bytes)).map(drop).ok_or(0)
+    }
+}
+
+#[cfg(feature = "ascii")]
+unsafe impl TryFromBytes for core::ascii::Char {
+    #[inline]
+    fn validate_bytes(bytes: &[u8]) -> Result<(), usize> {
+        if bytes[0] < 0x80 {
+            Ok(())
+        } else {
+            Err(0)
+        }
+    }
+}
+
+macro_rules! impl_try_from_bytes_array {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            unsafe impl<const N: usize> TryFromBytes for [$ty; N] {
+                #[inline]
+                fn validate_bytes(bytes: &[u8]) -> Result<(), usize> {
+                    bytes
+                        .chunks_exact(size_of::<$ty>())
+                        .enumerate()
+                        .try_for_each(|(index, bytes)| {
+                            <$ty as TryFromBytes>::validate_bytes(bytes)
+                                .map_err(|offset| index * size_of::<$ty>() + offset)
+                        })
+                }
+            }
+        )*
+    };
+}
+impl_try_from_bytes_array!(bool, char, crate::AsciiChar);
+#[cfg(feature = "ascii")]
+impl_try_from_bytes_array!(core::ascii::Char);
+
+macro_rules! impl_try_from_bytes_non_zero {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            unsafe impl TryFromBytes for core::num::$ty {
+                #[inline]
+                fn validate_bytes(bytes: &[u8]) -> Result<(), usize> {
+                    if bytes.iter().any(|byte| *byte != 0) {
+                        Ok(())
+                    } else {
+                        Err(0)
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_try_from_bytes_non_zero!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+);
+
+/// Returns a reference to `bytes` as a `T` after checking the length, alignment and bit pattern.
+/// An invalid bit pattern is reported with the offset of the first invalid byte.
+#[inline]
+pub fn try_from_bytes<T>(bytes: &[u8]) -> Result<&T, CastError>
+where
+    T: TryFromBytes,
+{
+    if bytes.len() != size_of::<T>() {
+        return Err(CastError::LengthMismatch(LengthError {
+            len: bytes.len(),
+            size: size_of::<T>(),
+        }));
+    }
+    if !bytes.as_ptr().cast::<T>().is_aligned() {
+        return Err(CastError::Misaligned(AlignmentError {
+            align: align_of::<T>(),
+        }));
+    }
+    if let Err(index) = T::validate_bytes(bytes) {
+        return Err(CastError::Invalid(ValidityError { index }));
+    }
+    Ok(unsafe { &*bytes.as_ptr().cast() })
+}
+
+/// Views `bytes` as a `str` after checking that it is ASCII, which is faster than
+/// [`core::str::from_utf8`]. Generic code gets the same check from the [`TryTransmuteRefFrom`] impl
+/// of [`AsciiStr`](crate::AsciiStr).
+#[inline]
+pub fn try_transmute_ascii_str(bytes: &[u8]) -> Result<&str, ValidityError> {
+    validate_bytes(bytes, 0x80)?;
+    Ok(unsafe { core::str::from_utf8_unchecked(bytes) })
+}
+
+/// Same as [`try_transmute_ascii_str`], except that the bytes stay mutable through the `str`
+#[inline]
+pub fn try_transmute_ascii_str_mut(bytes: &mut [u8]) -> Result<&mut str, ValidityError> {
+    validate_bytes(bytes, 0x80)?;
+    Ok(unsafe { core::str::from_utf8_unchecked_mut(bytes) })
+}
+
+/// Converts `bytes` to a `String` in place after checking that it is UTF-8, returning it with the
+/// error otherwise
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn try_transmute_string(bytes: Vec<u8>) -> Result<String, BufferError<Vec<u8>>> {
+    String::from_utf8(bytes).map_err(|error| BufferError {
+        error: ValidityError {
+            index: error.utf8_error().valid_up_to(),
+        },
+        buffer: error.into_bytes(),
+    })
+}
+
+/// Same as [`try_transmute_string`], except that `bytes` must be ASCII, which is faster to check
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn try_transmute_ascii_string(bytes: Vec<u8>) -> Result<String, BufferError<Vec<u8>>> {
+    match validate_bytes(&bytes, 0x80) {
+        Ok(()) => Ok(unsafe { String::from_utf8_unchecked(bytes) }),
+        Err(error) => Err(BufferError {
+            buffer: bytes,
+            error,
+        }),
+    }
+}
+
+/// Same as [`try_transmute_string`], for boxed slices
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn try_transmute_boxed_str(bytes: Box<[u8]>) -> Result<Box<str>, BufferError<Box<[u8]>>> {
+    match core::str::from_utf8(&bytes) {
+        Ok(_) => Ok(unsafe { alloc::str::from_boxed_utf8_unchecked(bytes) }),
+        Err(error) => Err(BufferError {
+            buffer: bytes,
+            error: ValidityError {
+                index: error.valid_up_to(),
+            },
+        }),
+    }
+}
+
+/// Same as [`try_transmute_ascii_string`], for boxed slices
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn try_transmute_ascii_boxed_str(bytes: Box<[u8]>) -> Result<Box<str>, BufferError<Box<[u8]>>> {
+    match validate_bytes(&bytes, 0x80) {
+        Ok(()) => Ok(unsafe { alloc::str::from_boxed_utf8_unchecked(bytes) }),
+        Err(error) => Err(BufferError {
+            buffer: bytes,
+            error,
+        }),
+    }
+}
+
+/// Calls `f` with the bytes of `str` and checks that they are still UTF-8 afterwards, even if `f`
+/// panics.
+///
+/// If they are not, everything from the first invalid byte onwards is set to zero, so `str` stays
+/// valid, and the index of that byte is returned as the error.
+pub fn with_str_bytes_mut<R>(
+    str: &mut str,
+    f: impl FnOnce(&mut [u8]) -> R,
+) -> Result<R, ValidityError> {
+    struct Restore<'a>(&'a mut [u8]);
+    impl Restore<'_> {
+        fn restore(&mut self) -> Result<(), ValidityError> {
+            match core::str::from_utf8(self.0) {
+                Ok(_) => Ok(()),
+                Err(error) => {
+                    let index = error.valid_up_to();
+                    self.0[index..].fill(0);
+                    Err(ValidityError { index })
+                }
+            }
+        }
+    }
+    impl Drop for Restore<'_> {
+        fn drop(&mut self) {
+            let _ = self.restore();
+        }
+    }
+
+    let mut restore = Restore(unsafe { str.as_bytes_mut() });
+    let result = f(restore.0);
+    let valid = restore.restore();
+    core::mem::forget(restore);
+    valid.map(|()| result)
+}
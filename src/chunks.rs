@@ -0,0 +1,112 @@
+use core::mem::size_of;
+
+use crate::{try_cast_slice, try_cast_slice_mut, CastError, Pod};
+
+/// Iterator over the `T`s at the start of a byte slice, see [`transmute_chunks_exact`]
+#[derive(Debug, Clone)]
+pub struct TransmuteChunksExact<'a, T> {
+    iter: core::slice::Iter<'a, T>,
+    remainder: &'a [u8],
+}
+
+/// Iterator over the `T`s at the start of a mutable byte slice, see
+/// [`transmute_chunks_exact_mut`]
+#[derive(Debug)]
+pub struct TransmuteChunksExactMut<'a, T> {
+    iter: core::slice::IterMut<'a, T>,
+    remainder: &'a mut [u8],
+}
+
+/// Splits `src` into as many `T`s as fit, which fails if `src` is not aligned for `T` or `T` is
+/// zero-sized
+#[inline]
+pub fn transmute_chunks_exact<T>(src: &[u8]) -> Result<TransmuteChunksExact<'_, T>, CastError>
+where
+    T: Pod,
+{
+    let len = src.len() - src.len() % size_of::<T>().max(1);
+    let (chunks, remainder) = src.split_at(len);
+    Ok(TransmuteChunksExact {
+        iter: try_cast_slice(chunks)?.iter(),
+        remainder,
+    })
+}
+
+/// Same as [`transmute_chunks_exact`], except that the `T`s and the remainder are mutable
+#[inline]
+pub fn transmute_chunks_exact_mut<T>(
+    src: &mut [u8],
+) -> Result<TransmuteChunksExactMut<'_, T>, CastError>
+where
+    T: Pod,
+{
+    let len = src.len() - src.len() % size_of::<T>().max(1);
+    let (chunks, remainder) = src.split_at_mut(len);
+    Ok(TransmuteChunksExactMut {
+        iter: try_cast_slice_mut(chunks)?.iter_mut(),
+        remainder,
+    })
+}
+
+impl<'a, T> TransmuteChunksExact<'a, T> {
+    /// The bytes after the last `T`, which are fewer than the size of `T`
+    #[inline]
+    pub fn remainder(&self) -> &'a [u8] {
+        self.remainder
+    }
+}
+
+impl<'a, T> TransmuteChunksExactMut<'a, T> {
+    /// The bytes after the last `T`, which are fewer than the size of `T`
+    #[inline]
+    pub fn into_remainder(self) -> &'a mut [u8] {
+        self.remainder
+    }
+}
+
+impl<'a, T> Iterator for TransmuteChunksExact<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> Iterator for TransmuteChunksExactMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for TransmuteChunksExact<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> DoubleEndedIterator for TransmuteChunksExactMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for TransmuteChunksExact<'_, T> {}
+impl<T> ExactSizeIterator for TransmuteChunksExactMut<'_, T> {}
+impl<T> core::iter::FusedIterator for TransmuteChunksExact<'_, T> {}
+impl<T> core::iter::FusedIterator for TransmuteChunksExactMut<'_, T> {}
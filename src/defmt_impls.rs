@@ -0,0 +1,102 @@
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_alias_defmt {
+    { $name:ident } => {
+        impl $crate::__private::defmt::Format for $name {
+            #[inline]
+            fn format(&self, f: $crate::__private::defmt::Formatter<'_>) {
+                $crate::__private::defmt::Format::format(self.as_str(), f)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_alias_defmt {
+    { $($alias:tt)* } => {
+        ::core::compile_error!(
+            "the `defmt` option of `enum_alias!` requires the `defmt` feature of `transmute_guard`"
+        );
+    };
+}
+
+/// Implements `defmt::Format` for wrappers as their inner type through the transmute guards, e.g.
+/// `defmt_as_inner! { Meters => f32; }`.
+///
+/// Requires the `defmt` feature.
+#[cfg(feature = "defmt")]
+#[macro_export]
+macro_rules! defmt_as_inner {
+    {} => {};
+    { $(#[$attr:meta])* $name:ty => $inner:ty $(; $($rest:tt)*)? } => {
+        $(#[$attr])*
+        impl $crate::__private::defmt::Format for $name {
+            #[inline]
+            fn format(&self, f: $crate::__private::defmt::Formatter<'_>) {
+                let inner: &$inner = $crate::safe_transmute_ref(self);
+                $crate::__private::defmt::Format::format(inner, f)
+            }
+        }
+
+        $crate::defmt_as_inner! { $($($rest)*)? }
+    };
+}
+
+/// Implements `defmt::Format` for wrappers as their inner type through the transmute guards, e.g.
+/// `defmt_as_inner! { Meters => f32; }`.
+///
+/// Requires the `defmt` feature.
+#[cfg(not(feature = "defmt"))]
+#[macro_export]
+macro_rules! defmt_as_inner {
+    { $($input:tt)* } => {
+        ::core::compile_error!(
+            "`defmt_as_inner!` requires the `defmt` feature of `transmute_guard`"
+        );
+    };
+}
+
+#[cfg(feature = "defmt")]
+mod impls {
+    use defmt::{Format, Formatter};
+
+    use crate::{
+        text::{Latin1Str, Utf8Bytes},
+        AsciiAlpha, AsciiChar, AsciiDigit, AsciiStr, Be, HexDigit, Le, UniCaseAscii,
+    };
+
+    macro_rules! impl_format {
+        ($($ty:ty => |$value:ident| $format:expr),* $(,)?) => {
+            $(
+                impl Format for $ty {
+                    #[inline]
+                    fn format(&self, f: Formatter<'_>) {
+                        let $value = self;
+                        Format::format($format, f)
+                    }
+                }
+            )*
+        };
+    }
+    impl_format!(
+        AsciiChar => |value| &value.to_char(),
+        AsciiDigit => |value| &value.to_char(),
+        HexDigit => |value| &value.to_char(),
+        AsciiAlpha => |value| &value.to_char(),
+        AsciiStr => |value| value.as_str(),
+        UniCaseAscii<str> => |value| value.get(),
+        UniCaseAscii<AsciiStr> => |value| value.get(),
+        Utf8Bytes => |value| value.as_str(),
+        Latin1Str => |value| value.as_bytes(),
+    );
+
+    macro_rules! impl_format_endian {
+        ($($ty:ty),* $(,)?) => {
+            impl_format!($(Be<$ty> => |value| &value.get(), Le<$ty> => |value| &value.get()),*);
+        };
+    }
+    impl_format_endian!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+}
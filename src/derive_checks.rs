@@ -0,0 +1,134 @@
+//! Inputs that the derives must reject at compile time.
+//!
+//! `TransmuteGuard` needs `#[repr(transparent)]` with one field:
+//!
+//! ```compile_fail
+//! #[derive(transmute_guard::TransmuteGuard)]
+//! #[repr(C)]
+//! struct Meters(f32);
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(transmute_guard::TransmuteGuard)]
+//! #[repr(transparent)]
+//! struct Meters(f32, f32);
+//! ```
+//!
+//! `#[transmute_guard(from = "...")]` needs the same size, at least the same alignment and the
+//! same offsets:
+//!
+//! ```compile_fail
+//! #[repr(C, align(16))]
+//! struct Raw {
+//!     a: u32,
+//! }
+//!
+//! #[derive(transmute_guard::TransmuteGuard)]
+//! #[repr(C)]
+//! #[transmute_guard(from = Raw)]
+//! struct Cooked {
+//!     a: u32,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[repr(C)]
+//! struct Raw {
+//!     a: u8,
+//!     b: u8,
+//!     c: u16,
+//! }
+//!
+//! #[derive(transmute_guard::TransmuteGuard)]
+//! #[repr(C)]
+//! #[transmute_guard(from = Raw)]
+//! struct Cooked {
+//!     b: u8,
+//!     a: u8,
+//!     c: u16,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[repr(C, packed)]
+//! struct Raw {
+//!     a: u32,
+//! }
+//!
+//! #[derive(transmute_guard::TransmuteGuard)]
+//! #[repr(C)]
+//! #[transmute_guard(from = Raw)]
+//! struct Cooked {
+//!     a: u32,
+//! }
+//! ```
+//!
+//! `Packed` needs `#[repr(C, packed)]` and `Pod` fields, so there is no padding:
+//!
+//! ```compile_fail
+//! #[derive(Clone, Copy, transmute_guard::Packed)]
+//! #[repr(C)]
+//! struct Wire {
+//!     tag: u8,
+//!     len: u32,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(Clone, Copy, transmute_guard::Packed)]
+//! #[repr(C, packed(2))]
+//! struct Wire {
+//!     tag: u8,
+//!     len: u32,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(Clone, Copy, transmute_guard::Packed)]
+//! #[repr(C, packed)]
+//! struct Wire {
+//!     tag: bool,
+//!     len: u32,
+//! }
+//! ```
+//!
+//! `TryFromBytes` needs a defined layout and fields that implement it:
+//!
+//! ```compile_fail
+//! #[derive(transmute_guard::TryFromBytes)]
+//! struct Record {
+//!     kind: u8,
+//!     value: u32,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(transmute_guard::TryFromBytes)]
+//! enum Kind {
+//!     A,
+//!     B,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(transmute_guard::TryFromBytes)]
+//! #[repr(C)]
+//! struct Record {
+//!     kind: &'static u8,
+//! }
+//! ```
+//!
+//! `View` needs named fields that implement `TryFromBytes`:
+//!
+//! ```compile_fail
+//! #[derive(transmute_guard::View)]
+//! struct Packet {
+//!     kind: u8,
+//!     name: &'static str,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! #[derive(transmute_guard::View)]
+//! struct Packet(u8, u16);
+//! ```
@@ -0,0 +1,135 @@
+use core::fmt;
+
+use crate::{AlignmentError, CastError, Pod, Zeroable};
+
+/// Primitive numbers that can be stored in [`Be`] and [`Le`]
+pub trait EndianInt: Copy {
+    /// The byte array with the size of `Self`
+    type Bytes: Pod + Eq + core::hash::Hash + Default;
+}
+
+/// A number stored in big endian byte order, with an alignment of 1
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct Be<T>(T::Bytes)
+where
+    T: EndianInt;
+
+/// A number stored in little endian byte order, with an alignment of 1
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(transparent)]
+pub struct Le<T>(T::Bytes)
+where
+    T: EndianInt;
+
+unsafe impl<T> Zeroable for Be<T> where T: EndianInt {}
+unsafe impl<T> Zeroable for Le<T> where T: EndianInt {}
+unsafe impl<T> Pod for Be<T> where T: EndianInt + 'static {}
+unsafe impl<T> Pod for Le<T> where T: EndianInt + 'static {}
+
+macro_rules! impl_endian {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl EndianInt for $ty {
+                type Bytes = [u8; core::mem::size_of::<$ty>()];
+            }
+
+            crate::impl_transmute_guard! {
+                unsafe Be<$ty> => [u8; core::mem::size_of::<$ty>()];
+                unsafe [u8; core::mem::size_of::<$ty>()] => Be<$ty>;
+                unsafe Le<$ty> => [u8; core::mem::size_of::<$ty>()];
+                unsafe [u8; core::mem::size_of::<$ty>()] => Le<$ty>;
+            }
+
+            impl_endian!(@wrapper Be $ty from_be_bytes to_be_bytes);
+            impl_endian!(@wrapper Le $ty from_le_bytes to_le_bytes);
+        )*
+    };
+    (@wrapper $wrapper:ident $ty:ident $from_bytes:ident $to_bytes:ident) => {
+        impl $wrapper<$ty> {
+            #[inline]
+            pub const fn new(value: $ty) -> Self {
+                Self(value.$to_bytes())
+            }
+
+            #[inline]
+            pub const fn get(self) -> $ty {
+                $ty::$from_bytes(self.0)
+            }
+
+            #[inline]
+            pub const fn set(&mut self, value: $ty) {
+                self.0 = value.$to_bytes();
+            }
+
+            /// Converts every number of `slice` to native byte order in place, which fails if
+            /// `slice` is not aligned for the native type
+            #[inline]
+            pub fn to_native_in_place(slice: &mut [Self]) -> Result<&mut [$ty], CastError> {
+                if !slice.as_ptr().cast::<$ty>().is_aligned() {
+                    return Err(CastError::Misaligned(AlignmentError {
+                        align: core::mem::align_of::<$ty>(),
+                    }));
+                }
+                let values: &mut [$ty] = unsafe {
+                    core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len())
+                };
+                // Whole words are loaded and stored, so this is a no-op or a vectorized byte swap
+                for value in values.iter_mut() {
+                    *value = $ty::$from_bytes(value.to_ne_bytes());
+                }
+                Ok(values)
+            }
+
+            /// Converts every number of `values` from native byte order in place
+            #[inline]
+            pub fn from_native_in_place(values: &mut [$ty]) -> &mut [Self] {
+                for value in values.iter_mut() {
+                    *value = $ty::from_ne_bytes(value.$to_bytes());
+                }
+                unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len()) }
+            }
+        }
+
+        impl From<$ty> for $wrapper<$ty> {
+            #[inline]
+            fn from(value: $ty) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$wrapper<$ty>> for $ty {
+            #[inline]
+            fn from(value: $wrapper<$ty>) -> Self {
+                value.get()
+            }
+        }
+
+        impl fmt::Debug for $wrapper<$ty> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+impl_endian!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
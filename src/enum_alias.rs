@@ -0,0 +1,1122 @@
+/// Declares an enum with a subset of the variants of a fieldless `Copy` parent enum, e.g.
+/// `enum Alias: Parent as i32 = { A | B };`.
+///
+/// Every variant takes its discriminant from the parent through an `as` cast in const context, so
+/// parents with explicit or non-contiguous discriminants are supported. The repr after `as` must be
+/// the repr of the parent and defaults to `u8`. A repr with a different size or a larger alignment
+/// than the parent is rejected at compile time in every mode, a repr that only differs in
+/// signedness is not. The parent can be any type naming the enum, like `crate::proto::v2::Status`,
+/// an instantiated generic type alias or `<T as Trait>::Assoc`.
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy)]
+/// #[repr(u8)]
+/// enum Parent { A, B, C }
+///
+/// transmute_guard::enum_alias! {
+///     #[enum_alias(storage = u8)]
+///     enum Wide: Parent as u32 = { A | B };
+/// }
+/// ```
+///
+/// `try_from_repr` and `TryFrom<repr>` convert from the raw discriminant without the parent, and
+/// `from_parent_unchecked` converts without any check. When the discriminants of the alias are
+/// contiguous, `try_from_parent` is a single range check. `matches` tests whether a parent value is
+/// in the alias and `filter` keeps the values of an iterator of parents that are.
+///
+/// Aliases compare equal to their parent and implement `AsRef` and `Borrow` of it. Derive the same
+/// `Hash` and `Ord` as the parent to look up maps keyed by the alias with parent values.
+///
+/// Options are passed as `#[enum_alias(...)]` attributes before the enum:
+/// - `fn_vis = <vis>`: visibility of the generated methods, defaults to the visibility of the enum
+/// - `derive(...)`: replaces the default `Debug, PartialEq, Eq` derives, `Copy` and `Clone` are
+///   always derived
+/// - `display`: implements `Display` with the variant name
+/// - `from_str`: implements `FromStr` from the variant name
+/// - `ancestors(...)`: when the parent is itself an alias, also converts to and from each of its
+///   ancestors
+/// - `siblings(...)`: implements `TryFrom` in both directions with other aliases of the same parent
+/// - `subset_of(...)`: implements `From` into other aliases of the same parent that contain every
+///   variant, and `TryFrom` back
+/// - `serde`: implements `Serialize` and `Deserialize` through the parent, requires the `serde`
+///   feature
+/// - `defmt`: implements `defmt::Format` with the variant name, requires the `defmt` feature
+/// - `arbitrary`: implements `arbitrary::Arbitrary` choosing one of the variants, requires the
+///   `arbitrary` feature
+/// - `set = <ident> as <uint>`: declares a bitset of the variants backed by the integer, which
+///   defaults to `u64`
+/// - `storage = <int>`: stores the alias as a narrower integer than the parent, converting by
+///   matching instead of transmuting, so no transmute guards or `AsRef` and `Borrow` of the parent
+///   are implemented and `ancestors` and `subset_of` need aliases with the same storage
+/// - `non_exhaustive`: the parent is `#[non_exhaustive]`, so no exhaustive matches on it are
+///   generated
+///
+/// Variants accept `#[enum_alias(is = <ident>)]` to generate a `const fn <ident>(self) -> bool`
+/// predicate. Marking a variant `#[default]` derives `Default`. Ranges of variants like `A..=Z`
+/// are rejected, since a macro cannot see the variants of the parent.
+///
+/// `enum A: Parent = { ... } else enum B = { ... };` declares `B` as the complement of `A`, with
+/// the same attributes and options except `set`, since the set of `A` would be declared twice.
+/// Attributes after `else`, such as `#[enum_alias(set = BSet)]`, only apply to `B`.
+/// `A::partition` matches the parent exhaustively, so adding a parent variant that is in neither
+/// alias is a compile error. It is not generated for `non_exhaustive` parents, which can gain
+/// variants that are in neither. A variant that is in both aliases is a compile error either way.
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy)]
+/// #[repr(u8)]
+/// enum Parent { A, B, C }
+///
+/// transmute_guard::enum_alias! {
+///     enum Handled: Parent = { A | B } else enum Unhandled = { B | C };
+/// }
+/// ```
+///
+/// An `impl { ... }` block after the variants of either enum adds its items to an inherent impl of
+/// that enum.
+///
+/// With the `derive` feature, `#[attr::enum_alias(parent = "Parent")]` on a plain enum expands to
+/// the same alias.
+///
+/// Several aliases can be declared in one invocation. Attributes before a `{ ... }` group of
+/// aliases apply to every alias in it, and options of the alias itself take precedence. Each
+/// relation between two aliases must only be declared on one of them.
+#[macro_export]
+macro_rules! enum_alias {
+    {
+        @emit [$($opts:tt)*] [$($derive:tt)*] [$store:ident $layout:ident] [$fn_vis:vis] [$($meta:tt)*]
+        [$vis:vis] $name:ident: $ty:ty as $repr:ident = {$(
+            { [$($cfg:tt)*] [$($attr:tt)*] [$($is:tt)*] $variant:ident }
+        )*}
+    } => {
+        #[cfg_attr(any($(all($(all $cfg),*)),*), repr($store))]
+        #[derive(Copy, Clone, $($derive)*)]
+        $($meta)*
+        $vis enum $name {
+            $(
+                $(#[cfg $cfg])*
+                $($attr)*
+                $variant = <$ty>::$variant as $store,
+            )*
+        }
+
+        impl $name {
+            $fn_vis const COUNT: usize = {
+                let mut count = 0;
+                $(
+                    $(#[cfg $cfg])*
+                    {
+                        _ = <$name>::$variant;
+                        count += 1;
+                    }
+                )*
+                count
+            };
+
+            $fn_vis const VARIANTS: [Self; $name::COUNT] = [$(
+                $(#[cfg $cfg])*
+                <$name>::$variant,
+            )*];
+
+            #[inline]
+            $fn_vis fn iter() -> ::core::array::IntoIter<Self, { $name::COUNT }> {
+                Self::VARIANTS.into_iter()
+            }
+
+            #[inline]
+            $fn_vis const fn index(self) -> usize {
+                let mut index = 0;
+                while index < $name::COUNT {
+                    if Self::VARIANTS[index] as $repr == self as $repr {
+                        return index;
+                    }
+                    index += 1;
+                }
+                ::core::unreachable!()
+            }
+
+            #[inline]
+            $fn_vis const fn as_str(self) -> &'static str {
+                match self {
+                    $(
+                        $(#[cfg $cfg])*
+                        Self::$variant => ::core::stringify!($variant),
+                    )*
+                }
+            }
+
+            $(
+                $crate::enum_alias! { @is $fn_vis [$($cfg)*] $variant [$($is)*] }
+            )*
+
+            $crate::enum_alias! { @as_u8 $fn_vis $repr }
+
+            #[inline]
+            $fn_vis const fn as_repr(self) -> $repr {
+                self as $repr
+            }
+
+            $crate::enum_alias! {
+                @parent_methods $layout $fn_vis $name: $ty, { $({ [$($cfg)*] $variant })* }
+            }
+
+            #[inline]
+            $fn_vis const fn try_from_parent(
+                value: $ty,
+            ) -> ::core::result::Result<Self, $crate::EnumAliasError<$ty>> {
+                #[cfg(any($(all($(all $cfg),*)),*))]
+                {
+                    const RANGE: ::core::option::Option<($repr, $repr)> = {
+                        let mut min = $name::VARIANTS[0] as $repr;
+                        let mut max = min;
+                        let mut index = 1;
+                        while index < $name::COUNT {
+                            let discriminant = $name::VARIANTS[index] as $repr;
+                            if discriminant < min {
+                                min = discriminant;
+                            }
+                            if discriminant > max {
+                                max = discriminant;
+                            }
+                            index += 1;
+                        }
+                        if max.abs_diff(min) as u128 == ($name::COUNT - 1) as u128 {
+                            ::core::option::Option::Some((min, max))
+                        } else {
+                            ::core::option::Option::None
+                        }
+                    };
+                    if let ::core::option::Option::Some((min, max)) = RANGE {
+                        let discriminant = value as $repr;
+                        return if min <= discriminant && discriminant <= max {
+                            ::core::result::Result::Ok(unsafe {
+                                ::core::mem::transmute::<$store, Self>(discriminant as $store)
+                            })
+                        } else {
+                            ::core::result::Result::Err($crate::EnumAliasError {
+                                value,
+                                alias: ::core::stringify!($name),
+                            })
+                        };
+                    }
+                }
+                match value {
+                    $(
+                        $(#[cfg $cfg])*
+                        <$ty>::$variant => ::core::result::Result::Ok(<$name>::$variant),
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::result::Result::Err($crate::EnumAliasError {
+                        value,
+                        alias: ::core::stringify!($name),
+                    }),
+                }
+            }
+
+            #[inline]
+            $fn_vis const fn matches(value: $ty) -> bool {
+                match value {
+                    $(
+                        $(#[cfg $cfg])*
+                        <$ty>::$variant => true,
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+
+            #[inline]
+            $fn_vis fn filter<I>(values: I) -> impl ::core::iter::Iterator<Item = Self>
+            where
+                I: ::core::iter::IntoIterator<Item = $ty>,
+            {
+                values
+                    .into_iter()
+                    .filter_map(|value| Self::try_from_parent(value).ok())
+            }
+
+            #[inline]
+            $fn_vis const fn try_from_repr(
+                value: $repr,
+            ) -> ::core::result::Result<Self, $crate::EnumAliasError<$repr>> {
+                let mut index = 0;
+                while index < $name::COUNT {
+                    if Self::VARIANTS[index] as $repr == value {
+                        return ::core::result::Result::Ok(Self::VARIANTS[index]);
+                    }
+                    index += 1;
+                }
+                ::core::result::Result::Err($crate::EnumAliasError {
+                    value,
+                    alias: ::core::stringify!($name),
+                })
+            }
+        }
+
+        #[cfg(any($(all($(all $cfg),*)),*))]
+        const _: () = {$(
+            $(#[cfg $cfg])*
+            ::core::assert!(
+                <$name>::$variant as $repr == <$ty>::$variant as $repr,
+                ::core::concat!(
+                    "discriminant of `",
+                    ::core::stringify!($name),
+                    "::",
+                    ::core::stringify!($variant),
+                    "` does not match its parent",
+                ),
+            );
+        )*};
+
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$ty>() == ::core::mem::size_of::<$repr>()
+                && ::core::mem::align_of::<$ty>() >= ::core::mem::align_of::<$repr>(),
+            ::core::concat!(
+                "the parent of `",
+                ::core::stringify!($name),
+                "` does not have the layout of `",
+                ::core::stringify!($repr),
+                "`",
+            ),
+        );
+
+        $crate::enum_alias! { @parent_layout $layout $name: $ty, { $({ [$($cfg)*] $variant })* } }
+
+        impl ::core::convert::From<$name> for $ty {
+            #[inline]
+            fn from(value: $name) -> Self {
+                $name::as_parent(value)
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$ty> for $name {
+            #[inline]
+            fn eq(&self, other: &$ty) -> bool {
+                *self as $repr == *other as $repr
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$name> for $ty {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                other == self
+            }
+        }
+
+        impl ::core::convert::TryFrom<$ty> for $name {
+            type Error = $crate::EnumAliasError<$ty>;
+            #[inline]
+            fn try_from(value: $ty) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_from_parent(value)
+            }
+        }
+
+        impl ::core::convert::TryFrom<$repr> for $name {
+            type Error = $crate::EnumAliasError<$repr>;
+            #[inline]
+            fn try_from(value: $repr) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_from_repr(value)
+            }
+        }
+
+        $crate::enum_alias! { @display [$($opts)*] $name }
+        $crate::enum_alias! { @from_str [$($opts)*] $name { $({ [$($cfg)*] $variant })* } }
+        $crate::enum_alias! { @ancestors [$($opts)*] $name: $ty }
+        $crate::enum_alias! { @siblings [$($opts)*] $name: $ty }
+        $crate::enum_alias! { @serde [$($opts)*] $name: $ty }
+        $crate::enum_alias! { @defmt [$($opts)*] $name }
+        $crate::enum_alias! { @arbitrary [$($opts)*] $name }
+        $crate::enum_alias! { @set [$($opts)*] [$fn_vis] [$vis] $name }
+        $crate::enum_alias! { @subset_of [$($opts)*] $name: $ty, { $({ [$($cfg)*] $variant })* } }
+    };
+    {
+        @parent_methods transmute $fn_vis:vis $name:ident: $ty:ty, {
+            $({ [$($cfg:tt)*] $variant:ident })*
+        }
+    } => {
+        #[inline]
+        $fn_vis const fn as_parent(self) -> $ty {
+            #[cfg(any($(all($(all $cfg),*)),*))]
+            {
+                unsafe { ::core::mem::transmute::<Self, $ty>(self) }
+            }
+            #[cfg(not(any($(all($(all $cfg),*)),*)))]
+            {
+                match self {}
+            }
+        }
+
+        #[inline]
+        $fn_vis const fn as_parent_ref(&self) -> &$ty {
+            unsafe { &*(self as *const Self).cast::<$ty>() }
+        }
+
+        /// # Safety
+        /// `value` must be one of the variants of the alias
+        #[inline]
+        $fn_vis const unsafe fn from_parent_unchecked(value: $ty) -> Self {
+            #[cfg(any($(all($(all $cfg),*)),*))]
+            {
+                unsafe { ::core::mem::transmute::<$ty, Self>(value) }
+            }
+            #[cfg(not(any($(all($(all $cfg),*)),*)))]
+            {
+                ::core::mem::forget(value);
+                unsafe { ::core::hint::unreachable_unchecked() }
+            }
+        }
+    };
+    {
+        @parent_methods narrow $fn_vis:vis $name:ident: $ty:ty, {
+            $({ [$($cfg:tt)*] $variant:ident })*
+        }
+    } => {
+        #[inline]
+        $fn_vis const fn as_parent(self) -> $ty {
+            match self {
+                $(
+                    $(#[cfg $cfg])*
+                    Self::$variant => <$ty>::$variant,
+                )*
+            }
+        }
+
+        /// # Safety
+        /// `value` must be one of the variants of the alias
+        #[inline]
+        $fn_vis const unsafe fn from_parent_unchecked(value: $ty) -> Self {
+            match value {
+                $(
+                    $(#[cfg $cfg])*
+                    <$ty>::$variant => Self::$variant,
+                )*
+                #[allow(unreachable_patterns)]
+                _ => unsafe { ::core::hint::unreachable_unchecked() },
+            }
+        }
+    };
+    {
+        @parent_layout transmute $name:ident: $ty:ty, {
+            $({ [$($cfg:tt)*] $variant:ident })*
+        }
+    } => {
+        $crate::__record_guard_layout! { [] $name => $ty }
+        unsafe impl $crate::TransmuteGuard<$name> for $ty {}
+        unsafe impl $crate::SafeTransmuteFrom<$name> for $ty {
+            #[inline]
+            fn safe_transmute_from(value: $name) -> Self {
+                $name::as_parent(value)
+            }
+        }
+
+        #[cfg(any($(all($(all $cfg),*)),*))]
+        const _: () = {
+            ::core::assert!(
+                ::core::mem::size_of::<$name>() == ::core::mem::size_of::<$ty>(),
+                ::core::concat!("`", ::core::stringify!($name), "` and its parent have different sizes"),
+            );
+            ::core::assert!(
+                ::core::mem::align_of::<$name>() >= ::core::mem::align_of::<$ty>(),
+                ::core::concat!("`", ::core::stringify!($name), "` is less aligned than its parent"),
+            );
+            $(
+                $(#[cfg $cfg])*
+                ::core::assert!(
+                    ::core::matches!(<$name>::$variant.as_parent(), <$ty>::$variant),
+                    ::core::concat!(
+                        "discriminant of `",
+                        ::core::stringify!($name),
+                        "::",
+                        ::core::stringify!($variant),
+                        "` does not match its parent",
+                    ),
+                );
+            )*
+        };
+
+        impl ::core::convert::AsRef<$ty> for $name {
+            #[inline]
+            fn as_ref(&self) -> &$ty {
+                self.as_parent_ref()
+            }
+        }
+
+        impl ::core::borrow::Borrow<$ty> for $name {
+            #[inline]
+            fn borrow(&self) -> &$ty {
+                self.as_parent_ref()
+            }
+        }
+    };
+    { @parent_layout narrow $($alias:tt)* } => {};
+    { @display [display, $($scan:tt)*] $name:ident } => {
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad(self.as_str())
+            }
+        }
+    };
+    { @display [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @display [$($scan)*] $($alias)* }
+    };
+    { @display [] $($alias:tt)* } => {};
+    {
+        @from_str [from_str, $($scan:tt)*] $name:ident {
+            $({ [$($cfg:tt)*] $variant:ident })*
+        }
+    } => {
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseEnumAliasError;
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    $(
+                        $(#[cfg $cfg])*
+                        ::core::stringify!($variant) => ::core::result::Result::Ok(Self::$variant),
+                    )*
+                    _ => ::core::result::Result::Err($crate::ParseEnumAliasError {
+                        alias: ::core::stringify!($name),
+                    }),
+                }
+            }
+        }
+    };
+    { @from_str [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @from_str [$($scan)*] $($alias)* }
+    };
+    { @from_str [] $($alias:tt)* } => {};
+    { @ancestors [ancestors($($ancestor:ty),* $(,)?), $($scan:tt)*] $name:ident: $ty:ty } => {
+        $(
+            const _: () = ::core::assert!(
+                ::core::mem::size_of::<$name>() == 0
+                    || (::core::mem::size_of::<$name>() == ::core::mem::size_of::<$ancestor>()
+                        && ::core::mem::align_of::<$name>() >= ::core::mem::align_of::<$ancestor>()),
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($name),
+                    "` and `",
+                    ::core::stringify!($ancestor),
+                    "` have different storage",
+                ),
+            );
+            $crate::__record_guard_layout! { [] $name => $ancestor }
+            unsafe impl $crate::TransmuteGuard<$name> for $ancestor {}
+            unsafe impl $crate::SafeTransmuteFrom<$name> for $ancestor {
+                #[inline]
+                fn safe_transmute_from(value: $name) -> Self {
+                    <$ancestor>::from(<$ty>::from(value))
+                }
+            }
+
+            impl ::core::convert::From<$name> for $ancestor {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    <$ancestor>::from(<$ty>::from(value))
+                }
+            }
+
+            impl ::core::convert::TryFrom<$ancestor> for $name {
+                type Error = $crate::EnumAliasError<$ancestor>;
+                #[inline]
+                fn try_from(value: $ancestor) -> ::core::result::Result<Self, Self::Error> {
+                    match <$ty as ::core::convert::TryFrom<$ancestor>>::try_from(value) {
+                        ::core::result::Result::Ok(parent) => match Self::try_from_parent(parent) {
+                            ::core::result::Result::Ok(alias) => ::core::result::Result::Ok(alias),
+                            ::core::result::Result::Err(_) => ::core::result::Result::Err($crate::EnumAliasError {
+                                value,
+                                alias: ::core::stringify!($name),
+                            }),
+                        },
+                        ::core::result::Result::Err(_) => ::core::result::Result::Err($crate::EnumAliasError {
+                            value,
+                            alias: ::core::stringify!($name),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+    { @ancestors [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @ancestors [$($scan)*] $($alias)* }
+    };
+    { @ancestors [] $($alias:tt)* } => {};
+    { @siblings [siblings($($sibling:ty),* $(,)?), $($scan:tt)*] $name:ident: $ty:ty } => {
+        $(
+            $crate::enum_alias! { @try_from_sibling $name: $ty, $sibling }
+            $crate::enum_alias! { @try_from_sibling $sibling: $ty, $name }
+        )*
+    };
+    { @siblings [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @siblings [$($scan)*] $($alias)* }
+    };
+    { @siblings [] $($alias:tt)* } => {};
+    { @serde [serde, $($scan:tt)*] $name:ident: $ty:ty } => {
+        $crate::__enum_alias_serde! { $name: $ty }
+    };
+    { @serde [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @serde [$($scan)*] $($alias)* }
+    };
+    { @serde [] $($alias:tt)* } => {};
+    { @defmt [defmt, $($scan:tt)*] $name:ident } => {
+        $crate::__enum_alias_defmt! { $name }
+    };
+    { @defmt [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @defmt [$($scan)*] $($alias)* }
+    };
+    { @defmt [] $($alias:tt)* } => {};
+    { @arbitrary [arbitrary, $($scan:tt)*] $name:ident } => {
+        $crate::__enum_alias_arbitrary! { $name }
+    };
+    { @arbitrary [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @arbitrary [$($scan)*] $($alias)* }
+    };
+    { @arbitrary [] $($alias:tt)* } => {};
+    { @set [set = $set:ident as $bits:ident, $($scan:tt)*] [$fn_vis:vis] [$vis:vis] $name:ident } => {
+        #[repr(transparent)]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+        $vis struct $set($bits);
+
+        const _: () = ::core::assert!(
+            $name::COUNT <= <$bits>::BITS as usize,
+            ::core::concat!("`", ::core::stringify!($set), "` has too few bits for every variant"),
+        );
+
+        impl $set {
+            $fn_vis const EMPTY: Self = Self(0);
+            $fn_vis const ALL: Self = Self::from_slice(&$name::VARIANTS);
+
+            #[inline]
+            $fn_vis const fn from_variant(variant: $name) -> Self {
+                Self(1 << variant.index())
+            }
+
+            #[inline]
+            $fn_vis const fn from_slice(variants: &[$name]) -> Self {
+                let mut set = Self::EMPTY;
+                let mut index = 0;
+                while index < variants.len() {
+                    set.insert(variants[index]);
+                    index += 1;
+                }
+                set
+            }
+
+            #[inline]
+            $fn_vis const fn from_bits(bits: $bits) -> ::core::option::Option<Self> {
+                if bits & !Self::ALL.0 == 0 {
+                    ::core::option::Option::Some(Self(bits))
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+
+            #[inline]
+            $fn_vis const fn bits(self) -> $bits {
+                self.0
+            }
+
+            #[inline]
+            $fn_vis const fn len(self) -> usize {
+                self.0.count_ones() as usize
+            }
+
+            #[inline]
+            $fn_vis const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            #[inline]
+            $fn_vis const fn contains(self, variant: $name) -> bool {
+                self.0 & Self::from_variant(variant).0 != 0
+            }
+
+            /// Returns whether the variant was not already in the set
+            #[inline]
+            $fn_vis const fn insert(&mut self, variant: $name) -> bool {
+                let inserted = !self.contains(variant);
+                self.0 |= Self::from_variant(variant).0;
+                inserted
+            }
+
+            /// Returns whether the variant was in the set
+            #[inline]
+            $fn_vis const fn remove(&mut self, variant: $name) -> bool {
+                let removed = self.contains(variant);
+                self.0 &= !Self::from_variant(variant).0;
+                removed
+            }
+
+            #[inline]
+            $fn_vis const fn union(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+
+            #[inline]
+            $fn_vis const fn intersection(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+
+            #[inline]
+            $fn_vis const fn difference(self, other: Self) -> Self {
+                Self(self.0 & !other.0)
+            }
+
+            #[inline]
+            $fn_vis const fn complement(self) -> Self {
+                Self(Self::ALL.0 & !self.0)
+            }
+
+            #[inline]
+            $fn_vis fn iter(self) -> impl ::core::iter::Iterator<Item = $name> {
+                $name::iter().filter(move |&variant| self.contains(variant))
+            }
+        }
+
+        impl ::core::convert::From<$name> for $set {
+            #[inline]
+            fn from(variant: $name) -> Self {
+                Self::from_variant(variant)
+            }
+        }
+
+        impl ::core::iter::FromIterator<$name> for $set {
+            #[inline]
+            fn from_iter<I>(iter: I) -> Self
+            where
+                I: ::core::iter::IntoIterator<Item = $name>,
+            {
+                let mut set = Self::EMPTY;
+                ::core::iter::Extend::extend(&mut set, iter);
+                set
+            }
+        }
+
+        impl ::core::iter::Extend<$name> for $set {
+            #[inline]
+            fn extend<I>(&mut self, iter: I)
+            where
+                I: ::core::iter::IntoIterator<Item = $name>,
+            {
+                for variant in iter {
+                    self.insert(variant);
+                }
+            }
+        }
+    };
+    { @set [set = $set:ident, $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @set [set = $set as u64, $($scan)*] $($alias)* }
+    };
+    { @set [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @set [$($scan)*] $($alias)* }
+    };
+    { @set [] $($alias:tt)* } => {};
+    { @subset_of [subset_of($($superset:ty),* $(,)?), $($scan:tt)*] $name:ident: $ty:ty, $variants:tt } => {
+        $(
+            $crate::enum_alias! { @into_superset $name: $ty, $superset, $variants }
+        )*
+    };
+    { @subset_of [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @subset_of [$($scan)*] $($alias)* }
+    };
+    { @subset_of [] $($alias:tt)* } => {};
+    {
+        @into_superset $name:ident: $ty:ty, $superset:ty, {
+            $({ [$($cfg:tt)*] $variant:ident })*
+        }
+    } => {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$name>() == 0
+                || (::core::mem::size_of::<$name>() == ::core::mem::size_of::<$superset>()
+                    && ::core::mem::align_of::<$name>() >= ::core::mem::align_of::<$superset>()),
+            ::core::concat!(
+                "`",
+                ::core::stringify!($name),
+                "` and `",
+                ::core::stringify!($superset),
+                "` have different storage",
+            ),
+        );
+        $crate::__record_guard_layout! { [] $name => $superset }
+        unsafe impl $crate::TransmuteGuard<$name> for $superset {}
+        unsafe impl $crate::SafeTransmuteFrom<$name> for $superset {
+            #[inline]
+            fn safe_transmute_from(value: $name) -> Self {
+                <$superset>::from(value)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $superset {
+            #[inline]
+            fn from(value: $name) -> Self {
+                match value {
+                    $(
+                        $(#[cfg $cfg])*
+                        <$name>::$variant => <$superset>::$variant,
+                    )*
+                }
+            }
+        }
+
+        $crate::enum_alias! { @try_from_sibling $name: $ty, $superset }
+    };
+    { @try_from_sibling $name:ty: $ty:ty, $sibling:ty } => {
+        impl ::core::convert::TryFrom<$sibling> for $name {
+            type Error = $crate::EnumAliasError<$sibling>;
+            #[inline]
+            fn try_from(value: $sibling) -> ::core::result::Result<Self, Self::Error> {
+                match <$name as ::core::convert::TryFrom<$ty>>::try_from(<$ty>::from(value)) {
+                    ::core::result::Result::Ok(alias) => ::core::result::Result::Ok(alias),
+                    ::core::result::Result::Err(_) => ::core::result::Result::Err($crate::EnumAliasError {
+                        value,
+                        alias: ::core::stringify!($name),
+                    }),
+                }
+            }
+        }
+    };
+    { @is $vis:vis [$($cfg:tt)*] $variant:ident [$is:ident $($rest:ident)*] } => {
+        $(#[cfg $cfg])*
+        #[inline]
+        $vis const fn $is(self) -> bool {
+            ::core::matches!(self, Self::$variant)
+        }
+
+        $crate::enum_alias! { @is $vis [$($cfg)*] $variant [$($rest)*] }
+    };
+    { @is $vis:vis [$($cfg:tt)*] $variant:ident [] } => {};
+    { @as_u8 $vis:vis u8 } => {
+        #[inline]
+        $vis const fn as_u8(self) -> u8 {
+            self as u8
+        }
+
+        #[inline]
+        $vis const fn try_from_u8(
+            value: u8,
+        ) -> ::core::result::Result<Self, $crate::EnumAliasError<u8>> {
+            Self::try_from_repr(value)
+        }
+    };
+    { @as_u8 $vis:vis $repr:ident } => {};
+    {
+        @variants [$($done:tt)*] [$($cfg:tt)*] [$($attr:tt)*] [$($is:tt)*]
+        { #[cfg $next:tt] $($variants:tt)* } { $($alias:tt)* }
+    } => {
+        $crate::enum_alias! {
+            @variants [$($done)*] [$($cfg)* $next] [$($attr)*] [$($is)*]
+            { $($variants)* } { $($alias)* }
+        }
+    };
+    {
+        @variants [$($done:tt)*] [$($cfg:tt)*] [$($attr:tt)*] [$($is:tt)*]
+        { #[cfg_attr $($next:tt)*] $($variants:tt)* } { $($alias:tt)* }
+    } => {
+        $crate::enum_alias! {
+            @variants [$($done)*] [$($cfg)*] [$($attr)* #[cfg_attr $($next)*]] [$($is)*]
+            { $($variants)* } { $($alias)* }
+        }
+    };
+    {
+        @variants [$($done:tt)*] [$($cfg:tt)*] [$($attr:tt)*] [$($is:tt)*]
+        { #[default] $($variants:tt)* } { [$($opts:tt)*] $($alias:tt)* }
+    } => {
+        $crate::enum_alias! {
+            @variants [$($done)*] [$($cfg)*] [$($attr)* #[default]] [$($is)*]
+            { $($variants)* } { [$($opts)* derive_default,] $($alias)* }
+        }
+    };
+    {
+        @variants [$($done:tt)*] [$($cfg:tt)*] [$($attr:tt)*] [$($is:tt)*]
+        { #[enum_alias(is = $next:ident)] $($variants:tt)* } { $($alias:tt)* }
+    } => {
+        $crate::enum_alias! {
+            @variants [$($done)*] [$($cfg)*] [$($attr)*] [$($is)* $next]
+            { $($variants)* } { $($alias)* }
+        }
+    };
+    {
+        @variants [$($done:tt)*] [$($cfg:tt)*] [$($attr:tt)*] [$($is:tt)*]
+        { #[$next:meta] $($variants:tt)* } { $($alias:tt)* }
+    } => {
+        $crate::enum_alias! {
+            @variants [$($done)*] [$($cfg)*] [$($attr)* #[$next]] [$($is)*]
+            { $($variants)* } { $($alias)* }
+        }
+    };
+    {
+        @variants [$($done:tt)*] [$($cfg:tt)*] [$($attr:tt)*] [$($is:tt)*]
+        { $from:ident ..= $to:ident $($variants:tt)* } { $($alias:tt)* }
+    } => {
+        ::core::compile_error!(::core::concat!(
+            "`enum_alias!` cannot see the variants of the parent, list the variants from `",
+            ::core::stringify!($from),
+            "` to `",
+            ::core::stringify!($to),
+            "` one by one",
+        ));
+    };
+    {
+        @variants [$($done:tt)*] [$($cfg:tt)*] [$($attr:tt)*] [$($is:tt)*]
+        { $variant:ident $(| $($variants:tt)*)? } { $($alias:tt)* }
+    } => {
+        $crate::enum_alias! {
+            @variants [$($done)* { [$($cfg)*] [$($attr)*] [$($is)*] $variant }] [] [] []
+            { $($($variants)*)? } { $($alias)* }
+        }
+    };
+    { @variants [$($done:tt)*] [] [] [] {} { [$($opts:tt)*] $($alias:tt)* } } => {
+        $crate::enum_alias! { @fn_vis [$($opts)*] { [$($opts)*] $($alias)* = { $($done)* } } }
+    };
+    { @fn_vis [fn_vis = $fn_vis:vis, $($scan:tt)*] { [$($opts:tt)*] $($alias:tt)* } } => {
+        $crate::enum_alias! { @derive [$($opts)*] { [$($opts)*] [$fn_vis] $($alias)* } }
+    };
+    { @fn_vis [$skip:tt $($scan:tt)*] { $($alias:tt)* } } => {
+        $crate::enum_alias! { @fn_vis [$($scan)*] { $($alias)* } }
+    };
+    { @fn_vis [] { [$($opts:tt)*] [$($meta:tt)*] [$vis:vis] $($alias:tt)* } } => {
+        $crate::enum_alias! { @derive [$($opts)*] { [$($opts)*] [$vis] [$($meta)*] [$vis] $($alias)* } }
+    };
+    { @derive [derive($($derive:tt)*), $($scan:tt)*] { [$($opts:tt)*] $($alias:tt)* } } => {
+        $crate::enum_alias! { @derive_default [$($opts)*] { [$($opts)*] [$($derive)*] $($alias)* } }
+    };
+    { @derive [$skip:tt $($scan:tt)*] { $($alias:tt)* } } => {
+        $crate::enum_alias! { @derive [$($scan)*] { $($alias)* } }
+    };
+    { @derive [] { [$($opts:tt)*] $($alias:tt)* } } => {
+        $crate::enum_alias! {
+            @derive_default [$($opts)*] { [$($opts)*] [Debug, PartialEq, Eq] $($alias)* }
+        }
+    };
+    {
+        @derive_default [derive_default, $($scan:tt)*]
+        { [$($opts:tt)*] [$($derive:tt)*] $($alias:tt)* }
+    } => {
+        $crate::enum_alias! { @storage [$($opts)*] { [$($opts)*] [Default, $($derive)*] $($alias)* } }
+    };
+    { @derive_default [$skip:tt $($scan:tt)*] { $($alias:tt)* } } => {
+        $crate::enum_alias! { @derive_default [$($scan)*] { $($alias)* } }
+    };
+    { @derive_default [] { [$($opts:tt)*] $($alias:tt)* } } => {
+        $crate::enum_alias! { @storage [$($opts)*] { [$($opts)*] $($alias)* } }
+    };
+    {
+        @storage [storage = $store:ident, $($scan:tt)*]
+        { [$($opts:tt)*] [$($derive:tt)*] $($alias:tt)* }
+    } => {
+        $crate::enum_alias! { @emit [$($opts)*] [$($derive)*] [$store narrow] $($alias)* }
+    };
+    { @storage [$skip:tt $($scan:tt)*] { $($alias:tt)* } } => {
+        $crate::enum_alias! { @storage [$($scan)*] { $($alias)* } }
+    };
+    {
+        @storage [] {
+            [$($opts:tt)*] [$($derive:tt)*] $fn_vis:tt $meta:tt $vis:tt
+            $name:ident: $ty:ty as $repr:ident = $variants:tt
+        }
+    } => {
+        $crate::enum_alias! {
+            @emit [$($opts)*] [$($derive)*] [$repr transmute] $fn_vis $meta $vis
+            $name: $ty as $repr = $variants
+        }
+    };
+    { @restart $shared:tt } => {};
+    { @restart { [$($opts:tt)*] [$($meta:tt)*] } $($rest:tt)* } => {
+        $crate::enum_alias! { @parse { [$($opts)*] [$($meta)*] } [$($opts)*] [$($meta)*] $($rest)* }
+    };
+    {
+        @parse $shared:tt [$($opts:tt)*] [$($meta:tt)*]
+        #[enum_alias($($opt:tt)*)]
+        $($rest:tt)*
+    } => {
+        $crate::enum_alias! { @parse $shared [$($opt)*, $($opts)*] [$($meta)*] $($rest)* }
+    };
+    {
+        @parse $shared:tt [$($opts:tt)*] [$($meta:tt)*]
+        #[$next:meta]
+        $($rest:tt)*
+    } => {
+        $crate::enum_alias! { @parse $shared [$($opts)*] [$($meta)* #[$next]] $($rest)* }
+    };
+    {
+        @parse $shared:tt [$($opts:tt)*] [$($meta:tt)*]
+        { $($group:tt)* }
+        $($rest:tt)*
+    } => {
+        $crate::enum_alias! { @restart { [$($opts)*] [$($meta)*] } $($group)* }
+        $crate::enum_alias! { @restart $shared $($rest)* }
+    };
+    {
+        @parse $shared:tt [$($opts:tt)*] [$($meta:tt)*]
+        $vis:vis enum $name:ident: $ty:ty as $repr:ident = { $($variants:tt)* };
+        $($rest:tt)*
+    } => {
+        $crate::enum_alias! {
+            @variants [] [] [] [] { $($variants)* } { [$($opts)*] [$($meta)*] [$vis] $name: $ty as $repr }
+        }
+        $crate::enum_alias! { @restart $shared $($rest)* }
+    };
+    {
+        @parse $shared:tt [$($opts:tt)*] [$($meta:tt)*]
+        $vis:vis enum $name:ident: $ty:ty as $repr:ident = { $($variants:tt)* }
+        impl { $($items:tt)* }
+        $($rest:tt)*
+    } => {
+        impl $name {
+            $($items)*
+        }
+
+        $crate::enum_alias! {
+            @parse $shared [$($opts)*] [$($meta)*]
+            $vis enum $name: $ty as $repr = { $($variants)* } $($rest)*
+        }
+    };
+    {
+        @parse $shared:tt [$($opts:tt)*] [$($meta:tt)*]
+        $vis:vis enum $name:ident: $ty:ty as $repr:ident = { $($variants:tt)* }
+        else $(#[$($complement_attr:tt)*])*
+        $complement_vis:vis enum $complement:ident = { $($complement_variants:tt)* }
+        $(impl { $($complement_items:tt)* })?;
+        $($rest:tt)*
+    } => {
+        $(
+            impl $complement {
+                $($complement_items)*
+            }
+        )?
+
+        $crate::enum_alias! {
+            @parse $shared [$($opts)*] [$($meta)*]
+            $vis enum $name: $ty as $repr = { $($variants)* };
+        }
+        $crate::enum_alias! {
+            @without_set [] [$($opts)*] {
+                $shared [$($meta)*] $(#[$($complement_attr)*])*
+                $complement_vis enum $complement: $ty as $repr = { $($complement_variants)* };
+            }
+        }
+        $crate::enum_alias! {
+            @cfgs [] [] { $($variants)* } {
+                @cfgs [] [] { $($complement_variants)* } { @partition [$($opts)*] [$vis] $name $complement: $ty }
+            }
+        }
+        $crate::enum_alias! {
+            @cfgs [] [] { $($variants)* } { @disjoint $name $complement: $ty }
+        }
+        $crate::enum_alias! { @restart $shared $($rest)* }
+    };
+    {
+        @without_set [$($kept:tt)*] [set = $set:ident $(as $bits:ident)?, $($opts:tt)*]
+        $then:tt
+    } => {
+        $crate::enum_alias! { @without_set [$($kept)*] [$($opts)*] $then }
+    };
+    { @without_set [$($kept:tt)*] [$next:tt $($opts:tt)*] $then:tt } => {
+        $crate::enum_alias! { @without_set [$($kept)* $next] [$($opts)*] $then }
+    };
+    { @without_set [$($kept:tt)*] [] { $shared:tt [$($meta:tt)*] $($alias:tt)* } } => {
+        $crate::enum_alias! { @parse $shared [$($kept)*] [$($meta)*] $($alias)* }
+    };
+    {
+        @parse $shared:tt [$($opts:tt)*] [$($meta:tt)*]
+        $vis:vis enum $name:ident: $ty:ty = $($rest:tt)*
+    } => {
+        $crate::enum_alias! { @parse $shared [$($opts)*] [$($meta)*] $vis enum $name: $ty as u8 = $($rest)* }
+    };
+    {
+        @cfgs [$($done:tt)*] [$($cfg:tt)*] { #[cfg $next:tt] $($variants:tt)* } { $($then:tt)* }
+        $($acc:tt)*
+    } => {
+        $crate::enum_alias! {
+            @cfgs [$($done)*] [$($cfg)* $next] { $($variants)* } { $($then)* } $($acc)*
+        }
+    };
+    {
+        @cfgs [$($done:tt)*] [$($cfg:tt)*] { #[$next:meta] $($variants:tt)* } { $($then:tt)* }
+        $($acc:tt)*
+    } => {
+        $crate::enum_alias! { @cfgs [$($done)*] [$($cfg)*] { $($variants)* } { $($then)* } $($acc)* }
+    };
+    {
+        @cfgs [$($done:tt)*] [$($cfg:tt)*] { $variant:ident $(| $($variants:tt)*)? } { $($then:tt)* }
+        $($acc:tt)*
+    } => {
+        $crate::enum_alias! {
+            @cfgs [$($done)* { [$($cfg)*] $variant }] [] { $($($variants)*)? } { $($then)* } $($acc)*
+        }
+    };
+    { @cfgs [$($done:tt)*] [] {} { $($then:tt)* } $($acc:tt)* } => {
+        $crate::enum_alias! { $($then)* $($acc)* { $($done)* } }
+    };
+    { @partition [non_exhaustive, $($scan:tt)*] $($alias:tt)* } => {};
+    { @partition [$skip:tt $($scan:tt)*] $($alias:tt)* } => {
+        $crate::enum_alias! { @partition [$($scan)*] $($alias)* }
+    };
+    {
+        @partition [] [$vis:vis] $name:ident $complement:ident: $ty:ty {
+            $({ [$($cfg:tt)*] $variant:ident })*
+        } {
+            $({ [$($complement_cfg:tt)*] $complement_variant:ident })*
+        }
+    } => {
+        impl $name {
+            #[inline]
+            $vis const fn partition(value: $ty) -> ::core::result::Result<Self, $complement> {
+                match value {
+                    $(
+                        $(#[cfg $cfg])*
+                        <$ty>::$variant => ::core::result::Result::Ok(<$name>::$variant),
+                    )*
+                    $(
+                        $(#[cfg $complement_cfg])*
+                        <$ty>::$complement_variant => {
+                            ::core::result::Result::Err(<$complement>::$complement_variant)
+                        }
+                    )*
+                }
+            }
+        }
+    };
+    {
+        @disjoint $name:ident $complement:ident: $ty:ty { $({ [$($cfg:tt)*] $variant:ident })* }
+    } => {
+        $(
+            $(#[cfg $cfg])*
+            const _: () = ::core::assert!(
+                !<$complement>::matches(<$ty>::$variant),
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($variant),
+                    "` is in both `",
+                    ::core::stringify!($name),
+                    "` and `",
+                    ::core::stringify!($complement),
+                    "`",
+                ),
+            );
+        )*
+    };
+    {} => {};
+    { $($input:tt)* } => {
+        $crate::enum_alias! { @restart { [] [] } $($input)* }
+    };
+}
+
+/// Matches a parent value against the variants of an alias in a single `match` on the parent, e.g.
+/// `alias_match!(status => Alias: Status { A | B => 1, C => 2 } else 0)`.
+///
+/// Every variant must be in the alias, which is checked at compile time. Parent values that are
+/// not matched by any arm, including those outside of the alias, evaluate the `else` expression.
+#[macro_export]
+macro_rules! alias_match {
+    (
+        $value:expr => $alias:ty: $parent:ty {
+            $($($variant:ident)|+ $(if $guard:expr)? => $body:expr),* $(,)?
+        } else $else:expr
+    ) => {{
+        const { $($(let _ = <$alias>::$variant;)+)* }
+        match $value {
+            $($(<$parent>::$variant)|+ $(if $guard)? => $body,)*
+            #[allow(unreachable_patterns)]
+            _ => $else,
+        }
+    }};
+}
@@ -0,0 +1,156 @@
+use core::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CastError {
+    /// The source is not aligned for the destination type
+    Misaligned(AlignmentError),
+    /// The source length in bytes does not match the size of the destination, or is not a multiple
+    /// of the destination element size
+    LengthMismatch(LengthError),
+    /// Exactly one of the two element types is zero-sized
+    SizeMismatch,
+    /// The source has fewer bytes left than the destination needs
+    OutOfBounds(LengthError),
+    /// The source bytes are not a valid bit pattern for the destination type
+    Invalid(ValidityError),
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Misaligned(_) => "source is not aligned for the destination type",
+            Self::LengthMismatch(_) => "source length does not match the destination size",
+            Self::SizeMismatch => "cannot cast between zero-sized and non-zero-sized types",
+            Self::OutOfBounds(_) => "source has fewer bytes left than the destination needs",
+            Self::Invalid(_) => "source is not a valid bit pattern for the destination type",
+        })
+    }
+}
+
+impl core::error::Error for CastError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Misaligned(error) => Some(error),
+            Self::LengthMismatch(error) | Self::OutOfBounds(error) => Some(error),
+            Self::SizeMismatch => None,
+            Self::Invalid(error) => Some(error),
+        }
+    }
+}
+
+impl From<AlignmentError> for CastError {
+    #[inline]
+    fn from(error: AlignmentError) -> Self {
+        Self::Misaligned(error)
+    }
+}
+
+impl From<ValidityError> for CastError {
+    #[inline]
+    fn from(error: ValidityError) -> Self {
+        Self::Invalid(error)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AlignmentError {
+    /// Alignment in bytes that the address of the source is not a multiple of
+    pub align: usize,
+}
+
+impl fmt::Display for AlignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "address is not aligned to {} bytes", self.align)
+    }
+}
+
+impl core::error::Error for AlignmentError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LengthError {
+    /// Number of bytes that are available
+    pub len: usize,
+    /// Number of bytes that are needed, or whose multiple is needed
+    pub size: usize,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} bytes do not fit a destination of {} bytes",
+            self.len, self.size
+        )
+    }
+}
+
+impl core::error::Error for LengthError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ValidityError {
+    /// Index of the first element that is not a valid bit pattern for the destination type
+    pub index: usize,
+}
+
+impl fmt::Display for ValidityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid bit pattern at index {}", self.index)
+    }
+}
+
+impl core::error::Error for ValidityError {}
+
+/// A [`ValidityError`] together with the buffer that failed to convert, so it is not lost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferError<T> {
+    pub buffer: T,
+    pub error: ValidityError,
+}
+
+impl<T> fmt::Display for BufferError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl<T> core::error::Error for BufferError<T>
+where
+    T: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EnumAliasError<T> {
+    /// The parent value that is not a variant of the alias
+    pub value: T,
+    /// Name of the alias
+    pub alias: &'static str,
+}
+
+impl<T> fmt::Display for EnumAliasError<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{:?}` is not a variant of `{}`", self.value, self.alias)
+    }
+}
+
+impl<T> core::error::Error for EnumAliasError<T> where T: fmt::Debug {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseEnumAliasError {
+    /// Name of the alias
+    pub alias: &'static str,
+}
+
+impl fmt::Display for ParseEnumAliasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not a variant name of `{}`", self.alias)
+    }
+}
+
+impl core::error::Error for ParseEnumAliasError {}
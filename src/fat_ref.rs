@@ -0,0 +1,56 @@
+use crate::text::{Latin1Str, Utf8Bytes};
+use crate::{
+    safe_transmute_ref, AsciiAlpha, AsciiChar, AsciiDigit, AsciiStr, HexDigit, SafeTransmuteFrom,
+    TransmuteGuard, UniCaseAscii,
+};
+
+// References to these pointees have the same metadata, so the references themselves can be
+// transmuted, which lets `safe_transmute_slice` convert tables of them
+macro_rules! impl_ref_guards {
+    ($($(#[$meta:meta])* $From:ty => $To:ty;)*) => {$(
+        $(#[$meta])*
+        unsafe impl<'a> TransmuteGuard<&'a $From> for &'a $To {}
+        $(#[$meta])*
+        unsafe impl<'a> SafeTransmuteFrom<&'a $From> for &'a $To {
+            #[inline]
+            fn safe_transmute_from(value: &'a $From) -> Self {
+                safe_transmute_ref(value)
+            }
+        }
+    )*};
+}
+
+impl_ref_guards! {
+    str => [u8];
+    [AsciiChar] => [u8];
+    [AsciiChar] => str;
+    [AsciiChar] => AsciiStr;
+    AsciiStr => [AsciiChar];
+    AsciiStr => str;
+    AsciiStr => [u8];
+    [AsciiDigit] => [u8];
+    [AsciiDigit] => [AsciiChar];
+    [AsciiDigit] => AsciiStr;
+    [AsciiDigit] => str;
+    [HexDigit] => [u8];
+    [HexDigit] => [AsciiChar];
+    [HexDigit] => AsciiStr;
+    [HexDigit] => str;
+    [AsciiAlpha] => [u8];
+    [AsciiAlpha] => [AsciiChar];
+    [AsciiAlpha] => AsciiStr;
+    [AsciiAlpha] => str;
+    str => UniCaseAscii<str>;
+    UniCaseAscii<str> => str;
+    AsciiStr => UniCaseAscii<AsciiStr>;
+    UniCaseAscii<AsciiStr> => AsciiStr;
+    [u8] => Latin1Str;
+    Latin1Str => [u8];
+    Utf8Bytes => [u8];
+    Utf8Bytes => str;
+    str => Utf8Bytes;
+    #[cfg(feature = "ascii")]
+    [core::ascii::Char] => [u8];
+    #[cfg(feature = "ascii")]
+    [core::ascii::Char] => str;
+}
@@ -0,0 +1,97 @@
+use crate::{transmute_unchecked, TransmuteGuard};
+
+/// Marks a Rust type that has the layout of the C type `Ffi`, such as a struct generated by
+/// bindgen, and converts between them without copying. Implement it with
+/// [`ffi_safe_transmute!`](crate::ffi_safe_transmute), which checks the layout.
+///
+/// # Safety
+/// Only implement this trait if `Self` and `Ffi` have the same layout and every value of each is a
+/// valid value of the other
+pub unsafe trait FfiSafeTransmute<Ffi>: Sized {
+    #[inline]
+    fn into_ffi(self) -> Ffi {
+        unsafe { transmute_unchecked(self) }
+    }
+
+    #[inline]
+    fn from_ffi(ffi: Ffi) -> Self {
+        unsafe { transmute_unchecked(ffi) }
+    }
+
+    #[inline]
+    fn as_ffi(&self) -> &Ffi {
+        unsafe { &*core::ptr::from_ref(self).cast() }
+    }
+
+    #[inline]
+    fn as_ffi_mut(&mut self) -> &mut Ffi {
+        unsafe { &mut *core::ptr::from_mut(self).cast() }
+    }
+
+    #[inline]
+    fn from_ffi_ref(ffi: &Ffi) -> &Self {
+        unsafe { &*core::ptr::from_ref(ffi).cast() }
+    }
+
+    #[inline]
+    fn from_ffi_mut(ffi: &mut Ffi) -> &mut Self {
+        unsafe { &mut *core::ptr::from_mut(ffi).cast() }
+    }
+}
+
+/// Checks that a Rust struct has the layout of a `#[repr(C)]` struct from bindgen and implements
+/// [`FfiSafeTransmute`](crate::FfiSafeTransmute) and the transmute guards in both directions, e.g.
+/// `ffi_safe_transmute! { Point <=> sys::point_t { x => x, y => y_coord } }`.
+///
+/// Every field of both structs must be listed once, paired with the field at the same offset, and
+/// every pair needs the guards in both directions. Sizes, alignments and offsets are compared at
+/// compile time, so a changed header fails to compile instead of corrupting values.
+#[macro_export]
+macro_rules! ffi_safe_transmute {
+    {} => {};
+    {
+        $($name:ident)::+ <=> $ffi:path { $($field:ident => $ffi_field:ident),* $(,)? }
+        $(; $($rest:tt)*)?
+    } => {
+        const _: () = {
+            type Name = $($name)::+;
+
+            #[allow(dead_code)]
+            fn exhaustive(value: Name, ffi: $ffi) {
+                let Name { $($field: _),* } = value;
+                let $ffi { $($ffi_field: _),* } = ffi;
+            }
+
+            #[allow(dead_code)]
+            fn guarded(value: &Name, ffi: &$ffi) {
+                $($crate::__private::assert_bidirectional_guard(&value.$field, &ffi.$ffi_field);)*
+            }
+
+            $(
+                ::core::assert!(
+                    ::core::mem::offset_of!(Name, $field)
+                        == ::core::mem::offset_of!($ffi, $ffi_field),
+                    ::core::concat!(
+                        "`", ::core::stringify!($field), "` and `", ::core::stringify!($ffi_field),
+                        "` have different offsets",
+                    ),
+                );
+            )*
+        };
+
+        $crate::impl_transmute_guard! { unsafe bidirectional $($name)::+ <=> $ffi }
+
+        unsafe impl $crate::FfiSafeTransmute<$ffi> for $($name)::+ {}
+
+        $crate::ffi_safe_transmute! { $($($rest)*)? }
+    };
+}
+
+#[doc(hidden)]
+#[inline]
+pub const fn assert_bidirectional_guard<A, B>(_: &A, _: &B)
+where
+    A: TransmuteGuard<B>,
+    B: TransmuteGuard<A>,
+{
+}
@@ -0,0 +1,208 @@
+#[cfg(feature = "alloc")]
+use alloc::{sync::Arc, vec::Vec};
+use core::{cell::Cell, mem::size_of};
+
+use crate::{get_transmute, get_transmute_mut, try_cast_slice, CastError, LengthError, Pod};
+
+/// Owned byte buffer, such as an array or a `Vec<u8>`, that typed values are split off the front
+/// of without copying.
+///
+/// The values borrow the buffer, since an array moves with the buffer. Splitting only borrows it
+/// shared, so the values split off with [`GuardedBytes::split_to_transmute`] can be used together.
+/// Under `alloc`, `GuardedBytes::freeze_shared` turns a `Vec<u8>` into reference counted typed
+/// views instead.
+#[derive(Debug, Clone, Default)]
+pub struct GuardedBytes<B> {
+    buf: B,
+    start: Cell<usize>,
+}
+
+impl<B> GuardedBytes<B>
+where
+    B: AsRef<[u8]>,
+{
+    #[inline]
+    pub const fn new(buf: B) -> Self {
+        Self {
+            buf,
+            start: Cell::new(0),
+        }
+    }
+
+    /// The bytes that have not been split off
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf.as_ref()[self.start.get()..]
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+
+    /// Splits the `T` off the front, which fails if there are not enough bytes or they are not
+    /// aligned for `T`
+    #[inline]
+    pub fn split_to_transmute<T>(&self) -> Result<&T, CastError>
+    where
+        T: Pod,
+    {
+        let value = get_transmute(self.buf.as_ref(), self.start.get())?;
+        self.start.set(self.start.get() + size_of::<T>());
+        Ok(value)
+    }
+
+    /// Same as [`GuardedBytes::split_to_transmute`], for `len` values
+    #[inline]
+    pub fn split_to_transmute_slice<T>(&self, len: usize) -> Result<&[T], CastError>
+    where
+        T: Pod,
+    {
+        let bytes = self.as_bytes();
+        let size = len.saturating_mul(size_of::<T>());
+        if size > bytes.len() {
+            return Err(CastError::OutOfBounds(LengthError {
+                len: bytes.len(),
+                size,
+            }));
+        }
+        let values = try_cast_slice(&bytes[..size])?;
+        self.start.set(self.start.get() + size);
+        Ok(values)
+    }
+
+    /// Views the bytes that have not been split off as `T`s, which fails if they are not aligned
+    /// for `T` or are not a multiple of its size
+    #[inline]
+    pub fn freeze_as<T>(&self) -> Result<&[T], CastError>
+    where
+        T: Pod,
+    {
+        try_cast_slice(self.as_bytes())
+    }
+
+    /// The whole buffer, including the bytes that have been split off
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B> GuardedBytes<B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.buf.as_mut()[self.start.get()..]
+    }
+
+    /// Same as [`GuardedBytes::split_to_transmute`], but the value borrows the buffer exclusively
+    #[inline]
+    pub fn split_to_transmute_mut<T>(&mut self) -> Result<&mut T, CastError>
+    where
+        T: Pod,
+    {
+        let start = self.start.get_mut();
+        let value = get_transmute_mut(self.buf.as_mut(), *start)?;
+        *start += size_of::<T>();
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl GuardedBytes<Vec<u8>> {
+    /// Freezes the bytes that have not been split off as `T`s behind a reference count, which
+    /// fails like [`GuardedBytes::freeze_as`]. The `Vec` is kept, so nothing is copied.
+    #[inline]
+    pub fn freeze_shared<T>(self) -> Result<SharedSlice<T>, CastError>
+    where
+        T: Pod,
+    {
+        let len = self.freeze_as::<T>()?.len();
+        Ok(SharedSlice {
+            bytes: Arc::new(self.buf),
+            start: self.start.get(),
+            len,
+            _marker: core::marker::PhantomData,
+        })
+    }
+}
+
+/// Reference counted immutable `[T]` in the bytes of a `Vec<u8>`, see
+/// [`GuardedBytes::freeze_shared`]. Clones and [`SharedSlice::split_to`] share the bytes.
+#[cfg(feature = "alloc")]
+pub struct SharedSlice<T> {
+    bytes: Arc<Vec<u8>>,
+    // in bytes, always aligned for `T`
+    start: usize,
+    len: usize,
+    _marker: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SharedSlice<T>
+where
+    T: Pod,
+{
+    /// Splits off the first `at` values, leaving the rest in `self`
+    ///
+    /// # Panics
+    /// Panics if `at` is more than the length
+    #[inline]
+    pub fn split_to(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "split index is out of bounds");
+        let front = Self {
+            bytes: Arc::clone(&self.bytes),
+            start: self.start,
+            len: at,
+            _marker: core::marker::PhantomData,
+        };
+        self.start += at * size_of::<T>();
+        self.len -= at;
+        front
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> core::ops::Deref for SharedSlice<T>
+where
+    T: Pod,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        let ptr = self.bytes[self.start..].as_ptr().cast();
+        unsafe { core::slice::from_raw_parts(ptr, self.len) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Clone for SharedSlice<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            bytes: Arc::clone(&self.bytes),
+            start: self.start,
+            len: self.len,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> core::fmt::Debug for SharedSlice<T>
+where
+    T: Pod + core::fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&**self, f)
+    }
+}
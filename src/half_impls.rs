@@ -0,0 +1,14 @@
+use half::{bf16, f16};
+
+use crate::{Pod, Zeroable};
+
+// Every bit pattern is a float, so the raw bits convert both ways, including NaN payloads
+crate::impl_transmute_guard! {
+    unsafe bidirectional half::f16 <=> u16;
+    unsafe bidirectional half::bf16 <=> u16;
+}
+
+unsafe impl Zeroable for f16 {}
+unsafe impl Pod for f16 {}
+unsafe impl Zeroable for bf16 {}
+unsafe impl Pod for bf16 {}
@@ -0,0 +1,37 @@
+use heapless::{String, Vec};
+
+use crate::{safe_transmute, specialize::try_identity, SafeTransmuteFrom, ValidityError};
+
+/// Transmutes every element of `src` and keeps the capacity
+#[inline]
+pub fn safe_transmute_heapless_vec<Src, Dst, const N: usize>(src: Vec<Src, N>) -> Vec<Dst, N>
+where
+    Dst: SafeTransmuteFrom<Src>,
+{
+    let src = match try_identity(src) {
+        Ok(dst) => return dst,
+        Err(src) => src,
+    };
+    let mut dst = Vec::new();
+    for value in src {
+        // `dst` has the same capacity as `src`, so this never fails
+        let _ = dst.push(safe_transmute(value));
+    }
+    dst
+}
+
+/// Checks that `src` is UTF-8 and converts it to a `String` with the same capacity
+#[inline]
+pub fn try_transmute_heapless_string<const N: usize>(
+    src: Vec<u8, N>,
+) -> Result<String<N>, ValidityError> {
+    String::from_utf8(src).map_err(|err| ValidityError {
+        index: err.valid_up_to(),
+    })
+}
+
+/// Converts `src` to its bytes with the same capacity, which is always valid unlike the inverse
+#[inline]
+pub fn safe_transmute_heapless_string_bytes<const N: usize>(src: String<N>) -> Vec<u8, N> {
+    src.into_bytes()
+}
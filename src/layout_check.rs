@@ -0,0 +1,92 @@
+#[cfg(feature = "layout_check")]
+mod records {
+    use core::mem::{align_of, size_of};
+
+    /// The layouts of both sides of a guard declared through the macros of this crate
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct GuardLayout {
+        pub src: &'static str,
+        pub dst: &'static str,
+        pub src_size: usize,
+        pub dst_size: usize,
+        pub src_align: usize,
+        pub dst_align: usize,
+    }
+
+    impl GuardLayout {
+        #[doc(hidden)]
+        #[inline]
+        pub const fn new<Src, Dst>(src: &'static str, dst: &'static str) -> Self {
+            Self {
+                src,
+                dst,
+                src_size: size_of::<Src>(),
+                dst_size: size_of::<Dst>(),
+                src_align: align_of::<Src>(),
+                dst_align: align_of::<Dst>(),
+            }
+        }
+
+        /// Whether a value or reference can be transmuted from `src` to `dst` as far as the layout
+        /// is concerned, which is that the sizes match and `dst` is not more aligned
+        ///
+        /// A zero-sized `src` is always compatible, because the macros only accept it for enum
+        /// aliases without variants, which have no values.
+        #[inline]
+        pub const fn is_compatible(&self) -> bool {
+            self.src_size == 0
+                || (self.src_size == self.dst_size && self.src_align >= self.dst_align)
+        }
+    }
+
+    inventory::collect!(GuardLayout);
+
+    /// Every guard declared through the macros of this crate in any crate of the binary, except
+    /// the generic and `?Sized` ones
+    #[inline]
+    pub fn guard_layouts() -> impl Iterator<Item = &'static GuardLayout> {
+        inventory::iter::<GuardLayout>.into_iter()
+    }
+
+    /// # Panics
+    /// Panics if any guard from [`guard_layouts`] is not [`GuardLayout::is_compatible`]
+    pub fn verify_all_layouts() {
+        for layout in guard_layouts() {
+            assert!(
+                layout.is_compatible(),
+                "guard from `{}` (size {}, align {}) to `{}` (size {}, align {}) is unsound",
+                layout.src,
+                layout.src_size,
+                layout.src_align,
+                layout.dst,
+                layout.dst_size,
+                layout.dst_align,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "layout_check")]
+pub use records::{guard_layouts, verify_all_layouts, GuardLayout};
+
+#[cfg(feature = "layout_check")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __record_guard_layout {
+    { [$($attr:tt)*] $From:ty => $To:ty } => {
+        $($attr)*
+        $crate::__private::inventory::submit! {
+            $crate::GuardLayout::new::<$From, $To>(
+                ::core::stringify!($From),
+                ::core::stringify!($To),
+            )
+        }
+    };
+}
+
+#[cfg(not(feature = "layout_check"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __record_guard_layout {
+    { $($input:tt)* } => {};
+}
@@ -1,17 +1,233 @@
 #![no_std]
 #![cfg_attr(feature = "ascii", feature(ascii_char))]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(feature = "ptr_metadata", feature(ptr_metadata))]
+#![cfg_attr(feature = "transmute_from", feature(transmutability))]
+#![cfg_attr(feature = "specialization", feature(specialization))]
+#![cfg_attr(feature = "specialization", allow(incomplete_features))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod arbitrary_impls;
+mod arena;
+mod array;
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impls;
+mod ascii;
+mod assert;
+mod atomic;
+mod audit;
+mod bitflags_impls;
+mod bounded;
+#[cfg(feature = "bytes")]
+mod bytes_impls;
+mod checked;
+mod chunks;
+mod defmt_impls;
+#[cfg(all(doctest, feature = "derive"))]
+mod derive_checks;
+mod endian;
+mod enum_alias;
+mod error;
+mod fat_ref;
+mod ffi;
+mod guarded_bytes;
+#[cfg(feature = "half")]
+mod half_impls;
+#[cfg(feature = "heapless")]
+mod heapless_impls;
+mod layout_check;
+#[cfg(feature = "ptr_metadata")]
+mod metadata;
+#[cfg(feature = "net")]
+mod net;
+#[cfg(feature = "num_enum")]
+mod num_enum_impls;
+mod option;
+mod pod;
+mod project;
+mod reader;
+#[cfg(feature = "rkyv")]
+mod rkyv_impls;
+#[cfg(feature = "roundtrip")]
+mod roundtrip;
+mod scalar;
+mod scan;
+mod serde_impls;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "smallvec")]
+mod smallvec_impls;
+mod specialize;
+pub mod text;
+mod transmute_from;
+mod tuple;
+#[cfg(feature = "uuid")]
+mod uuid_impls;
+mod volatile;
+mod wrapper;
+mod writer;
+mod zeroable;
+mod zerocopy_impls;
+
+pub use arena::Arena;
+pub use array::{
+    flatten_array, flatten_array_mut, flatten_array_ref, flatten_slice, flatten_slice_mut,
+    try_unflatten_slice, try_unflatten_slice_mut, unflatten_array, unflatten_array_mut,
+    unflatten_array_ref,
+};
+#[cfg(feature = "arrayvec")]
+pub use arrayvec_impls::{
+    safe_transmute_array_string_bytes, safe_transmute_array_vec, try_transmute_array_string,
+};
+#[cfg(feature = "ascii")]
+pub use ascii::{try_transmute_str_ascii_chars, try_transmute_str_ascii_chars_mut};
+pub use ascii::{AsciiAlpha, AsciiChar, AsciiDigit, AsciiStr, AsciiWriter, HexDigit, UniCaseAscii};
+pub use atomic::{
+    atomic_from_mut, atomic_from_mut_slice, atomic_get_mut, atomic_get_mut_slice, Atomic,
+};
+#[cfg(feature = "bytes")]
+pub use bytes_impls::{freeze_typed, try_cast_bytes, try_cast_bytes_mut};
+pub use checked::{
+    try_from_bytes, try_transmute_ascii_str, try_transmute_ascii_str_mut, try_transmute_mut,
+    try_transmute_ref, try_transmute_slice, try_transmute_slice_mut, with_str_bytes_mut,
+    TryFromBytes, TryTransmuteMutFrom, TryTransmuteRefFrom, TryTransmuteSliceFrom,
+};
+#[cfg(feature = "alloc")]
+pub use checked::{
+    try_transmute_ascii_boxed_str, try_transmute_ascii_string, try_transmute_boxed_str,
+    try_transmute_string,
+};
+pub use chunks::{
+    transmute_chunks_exact, transmute_chunks_exact_mut, TransmuteChunksExact,
+    TransmuteChunksExactMut,
+};
+pub use endian::{Be, EndianInt, Le};
+pub use error::{
+    AlignmentError, BufferError, CastError, EnumAliasError, LengthError, ParseEnumAliasError,
+    ValidityError,
+};
+pub use ffi::FfiSafeTransmute;
+pub use guarded_bytes::GuardedBytes;
+#[cfg(feature = "alloc")]
+pub use guarded_bytes::SharedSlice;
+#[cfg(feature = "heapless")]
+pub use heapless_impls::{
+    safe_transmute_heapless_string_bytes, safe_transmute_heapless_vec,
+    try_transmute_heapless_string,
+};
+#[cfg(feature = "layout_check")]
+pub use layout_check::{guard_layouts, verify_all_layouts, GuardLayout};
+#[cfg(feature = "ptr_metadata")]
+pub use metadata::{safe_transmute_mut_dst, safe_transmute_ref_dst};
+#[cfg(feature = "net")]
+pub use net::{
+    ipv4_from_bytes, ipv4_to_bytes, ipv6_from_bytes, ipv6_to_bytes, socket_addr_v4_from_parts,
+    socket_addr_v4_to_parts,
+};
+#[cfg(feature = "alloc")]
+pub use option::safe_transmute_option_box;
+pub use option::{
+    safe_transmute_option_mut, safe_transmute_option_non_null, safe_transmute_option_ref,
+};
+pub use pod::{
+    bytes_of, bytes_of_mut, cast_mut, from_bytes, get_transmute, get_transmute_mut, put_transmute,
+    read_unaligned_array, read_unaligned_transmute, split_transmute_front,
+    split_transmute_front_mut, transmute_prefix_suffix, transmute_prefix_suffix_mut,
+    try_cast_slice, try_cast_slice_mut, Pod,
+};
+pub use project::FirstField;
+pub use reader::ByteReader;
+#[cfg(feature = "roundtrip")]
+pub use roundtrip::{check_ref_roundtrip, check_roundtrip};
+#[cfg(feature = "simd")]
+pub use simd::{
+    flatten_simd_slice, flatten_simd_slice_mut, transmute_array_to_simd, try_unflatten_simd_slice,
+    try_unflatten_simd_slice_mut,
+};
+#[cfg(feature = "smallvec")]
+pub use smallvec_impls::safe_transmute_small_vec;
+pub use volatile::VolatileRef;
+pub use wrapper::TransparentWrapper;
+#[cfg(feature = "alloc")]
+pub use writer::ByteVecWriter;
+pub use writer::ByteWriter;
+pub use zeroable::{fill_zero, zeroed, Zeroable};
+
+#[cfg(feature = "derive")]
+pub use transmute_guard_derive::{Packed, TransmuteGuard, TryFromBytes, View};
+
+#[cfg(feature = "derive")]
+pub mod attr {
+    pub use transmute_guard_derive::enum_alias;
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::ffi::assert_bidirectional_guard;
+    pub use crate::project::{assert_same_type, SameType};
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "bitflags")]
+    pub use bitflags;
+    #[cfg(feature = "audit")]
+    pub mod audit {
+        pub use crate::audit::{Audit, AuditBytes, AuditFallback};
+    }
+    #[cfg(feature = "transmute_from")]
+    pub use crate::transmute_from::{TransmutableRef, TransmutableRefAssumeSafety};
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+    #[cfg(feature = "layout_check")]
+    pub use inventory;
+    #[cfg(feature = "num_enum")]
+    pub use num_enum;
+    pub mod tuple {
+        pub use crate::tuple::{FieldsGuard, HomogeneousFields, ReprCTuple};
+    }
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "zerocopy")]
+    pub use zerocopy;
+}
+
+/// Mutable references are only transmuted if the guard is implemented in both directions, because
+/// the value can be written through the transmuted reference.
+///
 /// # Safety
-/// Only implement this trait if transmuting from `T` to `Self` and vice versa is safe
+/// Only implement this trait if transmuting from `T` to `Self` is safe
+#[diagnostic::on_unimplemented(
+    message = "`{T}` cannot be transmuted to `{Self}`, because there is no guard for it",
+    label = "no `TransmuteGuard<{T}>` for `{Self}`",
+    note = "declare it with `impl_transmute_guard! {{ unsafe {T} => {Self} }}` or a derive, only \
+            if `{Self}` has the layout of `{T}` and accepts all of its values"
+)]
 pub unsafe trait TransmuteGuard<T>
 where
     T: ?Sized,
 {
+    /// Checks in debug builds that the bytes of `value` are valid for `Self`, which
+    /// `impl_transmute_guard!` implements for the ref and mut conversions of the blanket impls
+    #[cfg(feature = "audit")]
+    #[doc(hidden)]
+    #[inline]
+    fn audit(_value: &T)
+    where
+        T: Sized,
+    {
+    }
 }
 unsafe impl<T> TransmuteGuard<T> for T where T: ?Sized {}
 
 /// # Safety
-/// Only implement this trait if transmuting from `T` to `Self` and vice versa is safe
+/// Only implement this trait if transmuting from `T` to `Self` is safe
+#[diagnostic::on_unimplemented(
+    message = "`{T}` cannot be transmuted to `{Self}` by value",
+    label = "no `SafeTransmuteFrom<{T}>` for `{Self}`",
+    note = "`impl_transmute_guard! {{ unsafe {T} => {Self} }}` implements it with the guard, only \
+            use it if `{Self}` has the layout of `{T}` and accepts all of its values"
+)]
 pub unsafe trait SafeTransmuteFrom<T>: TransmuteGuard<T> + Sized {
     fn safe_transmute_from(value: T) -> Self;
 }
@@ -23,7 +239,13 @@ unsafe impl<T> SafeTransmuteFrom<T> for T {
 }
 
 /// # Safety
-/// Only implement this trait if transmuting from `T` to `Self` and vice versa is safe
+/// Only implement this trait if transmuting from `&T` to `&Self` is safe
+#[diagnostic::on_unimplemented(
+    message = "`&{T}` cannot be transmuted to `&{Self}`",
+    label = "no `SafeTransmuteRefFrom<{T}>` for `{Self}`",
+    note = "this is implemented for every `TransmuteGuard<{T}>`, which can be declared with \
+            `impl_transmute_guard!`, a derive or an `unsafe impl` if it is sound"
+)]
 pub unsafe trait SafeTransmuteRefFrom<T>: TransmuteGuard<T>
 where
     T: ?Sized,
@@ -36,12 +258,27 @@ where
 {
     #[inline]
     fn safe_transmute_ref_from(value: &U) -> &Self {
+        #[cfg(feature = "audit")]
+        <T as TransmuteGuard<U>>::audit(value);
         safe_transmute_ref_const(value)
     }
 }
 
+/// ```compile_fail
+/// let mut value = true;
+/// let byte: &mut u8 = transmute_guard::safe_transmute_mut(&mut value);
+/// *byte = 2;
+/// ```
+///
 /// # Safety
-/// Only implement this trait if transmuting from `T` to `Self` and vice versa is safe
+/// Only implement this trait if transmuting from `&mut T` to `&mut Self` is safe, which means that
+/// transmuting from `T` to `Self` and vice versa is safe
+#[diagnostic::on_unimplemented(
+    message = "`&mut {T}` cannot be transmuted to `&mut {Self}`",
+    label = "no `SafeTransmuteMutFrom<{T}>` for `{Self}`",
+    note = "this needs the guard in both directions, because `{Self}` can be written back as \
+            `{T}`, which `impl_transmute_guard! {{ unsafe bidirectional .. }}` declares"
+)]
 pub unsafe trait SafeTransmuteMutFrom<T>: TransmuteGuard<T>
 where
     T: ?Sized,
@@ -51,47 +288,21 @@ where
 unsafe impl<T, U> SafeTransmuteMutFrom<U> for T
 where
     T: TransmuteGuard<U>,
+    U: TransmuteGuard<T>,
 {
     #[inline]
     fn safe_transmute_mut_from(value: &mut U) -> &mut Self {
+        #[cfg(feature = "audit")]
+        <T as TransmuteGuard<U>>::audit(value);
         safe_transmute_mut_const(value)
     }
 }
 
-#[cfg(feature = "ascii")]
-unsafe impl<U> TransmuteGuard<[U]> for str where core::ascii::Char: SafeTransmuteFrom<U> {}
-#[cfg(feature = "ascii")]
-unsafe impl<U> SafeTransmuteRefFrom<[U]> for str
-where
-    core::ascii::Char: SafeTransmuteFrom<U>,
-{
-    #[inline]
-    fn safe_transmute_ref_from(value: &[U]) -> &Self {
-        let u_ptr: *const [U] = core::ptr::from_ref(value);
-        let s_ptr: *const str = u_ptr as *const str;
-        unsafe { &*(s_ptr) }
-    }
-}
-#[cfg(feature = "ascii")]
-unsafe impl<U> SafeTransmuteMutFrom<[U]> for str
-where
-    core::ascii::Char: SafeTransmuteFrom<U>,
-{
-    #[inline]
-    fn safe_transmute_mut_from(value: &mut [U]) -> &mut Self {
-        let u_ptr: *mut [U] = core::ptr::from_mut(value);
-        let s_ptr: *mut str = u_ptr as *mut str;
-        unsafe { &mut *(s_ptr) }
-    }
-}
-
-#[cfg(feature = "ascii")]
-unsafe impl TransmuteGuard<core::ascii::Char> for u8 {}
-#[cfg(feature = "ascii")]
-unsafe impl SafeTransmuteFrom<core::ascii::Char> for u8 {
+unsafe impl TransmuteGuard<str> for [u8] {}
+unsafe impl SafeTransmuteRefFrom<str> for [u8] {
     #[inline]
-    fn safe_transmute_from(value: core::ascii::Char) -> Self {
-        value.to_u8()
+    fn safe_transmute_ref_from(value: &str) -> &Self {
+        value.as_bytes()
     }
 }
 
@@ -111,13 +322,101 @@ where
     Dst::safe_transmute_from(src)
 }
 
+#[inline]
+pub fn safe_transmute_option<Src, Dst>(src: Option<Src>) -> Option<Dst>
+where
+    Dst: SafeTransmuteFrom<Src>,
+{
+    let src = match specialize::try_identity(src) {
+        Ok(dst) => return dst,
+        Err(src) => src,
+    };
+    src.map(Dst::safe_transmute_from)
+}
+
+/// Transmutes both the value and the error, which can stay the same type
+#[inline]
+pub fn safe_transmute_result<Src, SrcErr, Dst, DstErr>(
+    src: Result<Src, SrcErr>,
+) -> Result<Dst, DstErr>
+where
+    Dst: SafeTransmuteFrom<Src>,
+    DstErr: SafeTransmuteFrom<SrcErr>,
+{
+    let src = match specialize::try_identity(src) {
+        Ok(dst) => return dst,
+        Err(src) => src,
+    };
+    src.map(Dst::safe_transmute_from)
+        .map_err(DstErr::safe_transmute_from)
+}
+
+#[inline]
+pub fn safe_transmute_array<Src, Dst, const N: usize>(src: [Src; N]) -> [Dst; N]
+where
+    Dst: SafeTransmuteFrom<Src>,
+{
+    let src = match specialize::try_identity(src) {
+        Ok(dst) => return dst,
+        Err(src) => src,
+    };
+    src.map(Dst::safe_transmute_from)
+}
+
+/// Keeps the allocation, which fails to compile if `Src` and `Dst` have different sizes or
+/// alignments, because it is freed with the layout of `Dst`
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn safe_transmute_vec<Src, Dst>(src: alloc::vec::Vec<Src>) -> alloc::vec::Vec<Dst>
+where
+    Dst: TransmuteGuard<Src>,
+{
+    const {
+        assert!(core::mem::size_of::<Src>() == core::mem::size_of::<Dst>());
+        assert!(core::mem::align_of::<Src>() == core::mem::align_of::<Dst>());
+    }
+    let mut src = core::mem::ManuallyDrop::new(src);
+    let (ptr, len, capacity) = (src.as_mut_ptr(), src.len(), src.capacity());
+    unsafe { alloc::vec::Vec::from_raw_parts(ptr.cast(), len, capacity) }
+}
+
+#[inline]
+pub const fn safe_transmute_const<Src, Dst>(src: Src) -> Dst
+where
+    Dst: SafeTransmuteFrom<Src>,
+{
+    unsafe { transmute_unchecked(src) }
+}
+
+/// # Safety
+/// Same as [`core::mem::transmute`], except that the size check is only done after monomorphization
+#[inline]
+pub(crate) const unsafe fn transmute_unchecked<Src, Dst>(src: Src) -> Dst {
+    #[repr(C)]
+    union Transmute<Src, Dst> {
+        src: core::mem::ManuallyDrop<Src>,
+        dst: core::mem::ManuallyDrop<Dst>,
+    }
+    const {
+        assert!(core::mem::size_of::<Src>() == core::mem::size_of::<Dst>());
+    }
+    let transmute = Transmute::<Src, Dst> {
+        src: core::mem::ManuallyDrop::new(src),
+    };
+    core::mem::ManuallyDrop::into_inner(unsafe { transmute.dst })
+}
+
 #[inline]
 pub fn safe_transmute_ref<Src, Dst>(src: &Src) -> &Dst
 where
     Dst: SafeTransmuteRefFrom<Src> + ?Sized,
     Src: ?Sized,
 {
-    Dst::safe_transmute_ref_from(src)
+    let dst = Dst::safe_transmute_ref_from(src);
+    // Also covers the impls for unsized types, which do not go through the blanket impls
+    #[cfg(feature = "audit")]
+    audit::audit_ref::<Src, Dst>(core::mem::size_of_val(src), dst);
+    dst
 }
 
 #[inline]
@@ -126,7 +425,12 @@ where
     Dst: SafeTransmuteMutFrom<Src> + ?Sized,
     Src: ?Sized,
 {
-    Dst::safe_transmute_mut_from(src)
+    #[cfg(feature = "audit")]
+    let src_size = core::mem::size_of_val(src);
+    let dst = Dst::safe_transmute_mut_from(src);
+    #[cfg(feature = "audit")]
+    audit::audit_ref::<Src, Dst>(src_size, dst);
+    dst
 }
 
 #[inline]
@@ -134,6 +438,8 @@ pub const fn safe_transmute_ref_const<Src, Dst>(src: &Src) -> &Dst
 where
     Dst: SafeTransmuteRefFrom<Src>,
 {
+    #[cfg(feature = "audit")]
+    audit::audit_layout::<Src, Dst>();
     let src_ptr = core::ptr::from_ref(src);
     let dst_ptr: *const Dst = src_ptr.cast();
     unsafe { &*dst_ptr }
@@ -144,6 +450,8 @@ pub const fn safe_transmute_mut_const<Src, Dst>(src: &mut Src) -> &mut Dst
 where
     Dst: SafeTransmuteMutFrom<Src>,
 {
+    #[cfg(feature = "audit")]
+    audit::audit_layout::<Src, Dst>();
     let src_ptr = core::ptr::from_mut(src);
     let dst_ptr: *mut Dst = src_ptr.cast();
     unsafe { &mut *dst_ptr }
@@ -154,116 +462,207 @@ pub const fn safe_transmute_slice<Src, Dst>(src: &[Src]) -> &[Dst]
 where
     Dst: TransmuteGuard<Src>,
 {
-    let u_ptr = core::ptr::from_ref(src);
-    let t_ptr = u_ptr as *const [Dst];
-    unsafe { &*t_ptr }
+    #[cfg(feature = "audit")]
+    audit::audit_layout::<Src, Dst>();
+    unsafe { core::slice::from_raw_parts(src.as_ptr().cast(), src.len()) }
 }
 
 #[inline]
 pub const fn safe_transmute_slice_mut<Src, Dst>(src: &mut [Src]) -> &mut [Dst]
 where
     Dst: TransmuteGuard<Src>,
+    Src: TransmuteGuard<Dst>,
 {
-    let u_ptr = core::ptr::from_mut(src);
-    let t_ptr = u_ptr as *mut [Dst];
-    unsafe { &mut *t_ptr }
+    #[cfg(feature = "audit")]
+    audit::audit_layout::<Src, Dst>();
+    unsafe { core::slice::from_raw_parts_mut(src.as_mut_ptr().cast(), src.len()) }
 }
 
+/// Implements `TransmuteGuard<From>` and `SafeTransmuteFrom<From>` for `To`, e.g.
+/// `impl_transmute_guard! { unsafe u8 => Byte }`.
+///
+/// `?Sized` only implements `TransmuteGuard`, `<T, ...>` with an optional `where` clause at the end
+/// declares generic impls, and `bidirectional A <=> B` implements both directions, where `A` must
+/// be a plain path. The ref and mut conversions come from the blanket impls of their traits.
+///
+/// Impls without generics fail to compile if the sizes differ or `To` is more aligned than `From`,
+/// and are recorded for `verify_all_layouts` with the `layout_check` feature.
+///
+/// Several impls can be separated by `;`, each with its own attributes like `#[cfg(...)]`.
 #[macro_export]
 macro_rules! impl_transmute_guard {
-    { unsafe ?Sized $From:ty => $To:ty } => {
-        unsafe impl $crate::TransmuteGuard<$From> for $To {}
+    {} => {};
+    {
+        $(#[$attr:meta])*
+        unsafe $(?$Sized:ident)? bidirectional $($A:ident)::+ <=> $B:ty $(; $($rest:tt)*)?
+    } => {
+        $crate::impl_transmute_guard! {
+            @impl [$(#[$attr])*] [$($Sized)?] [] $($A)::+ => $B []
+        }
+        $crate::impl_transmute_guard! {
+            @impl [$(#[$attr])*] [$($Sized)?] [] $B => $($A)::+ []
+        }
+        $crate::impl_transmute_guard! { $($($rest)*)? }
     };
-    { unsafe $From:ty => $To:ty } => {
-        unsafe impl $crate::TransmuteGuard<$From> for $To {}
-        unsafe impl $crate::SafeTransmuteFrom<$From> for $To {
-            #[inline]
-            fn safe_transmute_from(value: $From) -> Self {
-                unsafe { ::core::mem::transmute::<$From, $To>(value) }
-            }
+    {
+        $(#[$attr:meta])*
+        unsafe $(?$Sized:ident)? <$($param:ident),* $(,)?> $From:ty => $To:ty
+        $(; $($rest:tt)*)?
+    } => {
+        $crate::impl_transmute_guard! {
+            @impl [$(#[$attr])*] [$($Sized)?] [$($param),*] $From => $To []
         }
+        $crate::impl_transmute_guard! { $($($rest)*)? }
     };
-}
-
-#[macro_export]
-macro_rules! enum_alias {
     {
-        $(#[$meta:meta])*
-        $vis:vis enum $name:ident: $ty:ty = {$(
-            $variant0:ident $(| $variant:ident)*
-        )?};
+        $(#[$attr:meta])*
+        unsafe $(?$Sized:ident)? <$($param:ident),* $(,)?> $From:ty => $To:ty
+        where $($bound:tt)*
     } => {
-        #[repr(u8)]
-        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-        $(#[$meta])*
-        enum $name {
-            $(
-                $variant0 = <$ty>::$variant0 as u8,
-                $($variant = <$ty>::$variant as u8, )*
-            )?
+        $crate::impl_transmute_guard! {
+            @where { [$(#[$attr])*] [$($Sized)?] [$($param),*] $From => $To } [] $($bound)*
         }
-
-        impl $name {
+    };
+    { $(#[$attr:meta])* unsafe ?Sized $From:ty => $To:ty $(; $($rest:tt)*)? } => {
+        $crate::impl_transmute_guard! { @impl [$(#[$attr])*] [Sized] [] $From => $To [] }
+        $crate::impl_transmute_guard! { $($($rest)*)? }
+    };
+    { $(#[$attr:meta])* unsafe $From:ty => $To:ty $(; $($rest:tt)*)? } => {
+        $crate::impl_transmute_guard! { @impl [$(#[$attr])*] [] [] $From => $To [] }
+        $crate::impl_transmute_guard! { $($($rest)*)? }
+    };
+    { @where { $($head:tt)* } [$($bound:tt)*] ; $($rest:tt)* } => {
+        $crate::impl_transmute_guard! { @impl $($head)* [$($bound)*] }
+        $crate::impl_transmute_guard! { $($rest)* }
+    };
+    { @where $head:tt [$($bound:tt)*] $next:tt $($rest:tt)* } => {
+        $crate::impl_transmute_guard! { @where $head [$($bound)* $next] $($rest)* }
+    };
+    { @where { $($head:tt)* } [$($bound:tt)*] } => {
+        $crate::impl_transmute_guard! { @impl $($head)* [$($bound)*] }
+    };
+    {
+        @impl [$($attr:tt)*] [Sized] [$($param:ident),*] $From:ty => $To:ty [$($bound:tt)*]
+    } => {
+        $($attr)*
+        unsafe impl<$($param),*> $crate::TransmuteGuard<$From> for $To where $($bound)* {}
+    };
+    { @impl [$($attr:tt)*] [] [] $From:ty => $To:ty [] } => {
+        $($attr)*
+        const _: () = {
+            ::core::assert!(
+                ::core::mem::size_of::<$From>() == ::core::mem::size_of::<$To>(),
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($From),
+                    "` and `",
+                    ::core::stringify!($To),
+                    "` have different sizes",
+                ),
+            );
+            ::core::assert!(
+                ::core::mem::align_of::<$From>() >= ::core::mem::align_of::<$To>(),
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($To),
+                    "` is more aligned than `",
+                    ::core::stringify!($From),
+                    "`",
+                ),
+            );
+        };
+        $crate::__record_guard_layout! { [$($attr)*] $From => $To }
+        $($attr)*
+        unsafe impl $crate::TransmuteGuard<$From> for $To {
+            $crate::__audit_guard! { $From => $To }
+        }
+        $($attr)*
+        unsafe impl $crate::SafeTransmuteFrom<$From> for $To {
             #[inline]
-            $vis const fn as_u8(self) -> u8 {
-                self as u8
+            fn safe_transmute_from(value: $From) -> Self {
+                $crate::__audit_transmute! { $From => $To, &value }
+                unsafe { ::core::mem::transmute::<$From, $To>(value) }
             }
-
+        }
+    };
+    { @impl [$($attr:tt)*] [] [$($param:ident),*] $From:ty => $To:ty [$($bound:tt)*] } => {
+        $($attr)*
+        unsafe impl<$($param),*> $crate::TransmuteGuard<$From> for $To where $($bound)* {}
+        $($attr)*
+        unsafe impl<$($param),*> $crate::SafeTransmuteFrom<$From> for $To where $($bound)* {
             #[inline]
-            $vis const fn as_parent(self) -> $ty {
-                unsafe { ::core::mem::transmute(self) }
+            fn safe_transmute_from(value: $From) -> Self {
+                $crate::safe_transmute_const(value)
             }
+        }
+    };
+}
 
-            #[inline]
-            $vis const fn try_from_parent(value: $ty) -> ::core::result::Result<Self, ()> {
-                match value {
-                    $(
-                        <$ty>::$variant0 => ::core::result::Result::Ok(<$name>::$variant0),
-                        $(
-                            <$ty>::$variant => ::core::result::Result::Ok(<$name>::$variant),
-                        )*
-                        _ => ::core::result::Result::Err(()),
-                    )?
-                }
-            }
+/// Implements `TransmuteGuard` and `SafeTransmuteFrom` from the first to every later type of a
+/// chain of existing impls, e.g. `impl_transmute_chain! { A => B => C }` implements `A => C`.
+///
+/// `?Sized` only implements `TransmuteGuard`.
+#[macro_export]
+macro_rules! impl_transmute_chain {
+    { $(?Sized)? $A:ty => $B:ty } => {};
+    { ?Sized $A:ty => $B:ty => $C:ty $(=> $rest:ty)* } => {
+        $crate::assert_transmutable!(?Sized $A => $B);
+        $crate::assert_transmutable!(?Sized $B => $C);
+        unsafe impl $crate::TransmuteGuard<$A> for $C {}
 
+        $crate::impl_transmute_chain! { ?Sized $A => $C $(=> $rest)* }
+    };
+    { $A:ty => $B:ty => $C:ty $(=> $rest:ty)* } => {
+        unsafe impl $crate::TransmuteGuard<$A> for $C
+        where
+            $B: $crate::TransmuteGuard<$A>,
+            $C: $crate::TransmuteGuard<$B>,
+        {
         }
-
-        unsafe impl $crate::TransmuteGuard<$name> for $ty {}
-        unsafe impl $crate::SafeTransmuteFrom<$name> for $ty {
+        unsafe impl $crate::SafeTransmuteFrom<$A> for $C
+        where
+            $B: $crate::SafeTransmuteFrom<$A>,
+            $C: $crate::SafeTransmuteFrom<$B>,
+        {
             #[inline]
-            fn safe_transmute_from(value: $name) -> Self {
-                $name::as_parent(value)
+            fn safe_transmute_from(value: $A) -> Self {
+                <$C as $crate::SafeTransmuteFrom<$B>>::safe_transmute_from(
+                    <$B as $crate::SafeTransmuteFrom<$A>>::safe_transmute_from(value),
+                )
             }
         }
 
-        impl ::core::convert::From<$name> for $ty {
-            #[inline]
-            fn from(value: $name) -> Self {
-                #[cfg(debug_assertions)]
-                let self_dev: Self = match value {
-                    $(
-                        <$name>::$variant0 => <$ty>::$variant0,
-                        $(
-                            <$name>::$variant => <$ty>::$variant,
-                        )*
-                    )?
-                };
-                let self_prod: Self = $name::as_parent(value);
-                #[cfg(debug_assertions)]
-                {
-                    ::core::debug_assert_eq!(self_dev, self_prod, ::core::concat!(::core::stringify!(::core::convert::From<$name> for $ty)));
-                }
-                self_prod
-            }
-        }
+        $crate::impl_transmute_chain! { $A => $C $(=> $rest)* }
+    };
+}
 
-        impl ::core::convert::TryFrom<$ty> for $name {
-            type Error = ();
-            #[inline]
-            fn try_from(value: $ty) -> ::core::result::Result<Self, Self::Error> {
-                Self::try_from_parent(value)
-            }
-        }
+/// Safely transmutes a value, `&` or `&mut` into `Dst`, e.g. `cast!(&mut bytes => Bytes)`.
+#[macro_export]
+macro_rules! cast {
+    (&mut $src:expr => $Dst:ty) => {
+        $crate::safe_transmute_mut::<_, $Dst>(&mut $src)
+    };
+    (& $src:expr => $Dst:ty) => {
+        $crate::safe_transmute_ref::<_, $Dst>(&$src)
+    };
+    ($src:expr => $Dst:ty) => {
+        $crate::safe_transmute::<_, $Dst>($src)
     };
 }
+
+/// Implements the transmute guards in both directions between an integer newtype and its integer,
+/// `Wrapping` and `Saturating` of it, as well as `TransparentWrapper` of the integer, e.g.
+/// `impl_int_newtype_guards! { unsafe Meters => u32 }`. Slices convert with `safe_transmute_slice`.
+#[macro_export]
+macro_rules! impl_int_newtype_guards {
+    { $(unsafe $New:ty => $Int:ty);* $(;)? } => {$(
+        $crate::assert_same_layout!($New, $Int);
+        $crate::impl_transmute_guard! { unsafe $Int => $New }
+        $crate::impl_transmute_guard! { unsafe $New => $Int }
+        $crate::impl_transmute_guard! { unsafe ::core::num::Wrapping<$Int> => $New }
+        $crate::impl_transmute_guard! { unsafe $New => ::core::num::Wrapping<$Int> }
+        $crate::impl_transmute_guard! { unsafe ::core::num::Saturating<$Int> => $New }
+        $crate::impl_transmute_guard! { unsafe $New => ::core::num::Saturating<$Int> }
+        unsafe impl $crate::TransparentWrapper<$Int> for $New {}
+    )*};
+}
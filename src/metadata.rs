@@ -0,0 +1,80 @@
+use core::ptr::Pointee;
+
+use crate::TransmuteGuard;
+
+/// Same as [`safe_transmute_ref`](crate::safe_transmute_ref), except that `Src` and `Dst` can be
+/// any unsized types with the same pointer metadata, which is kept as is
+#[inline]
+pub const fn safe_transmute_ref_dst<Src, Dst>(src: &Src) -> &Dst
+where
+    Src: ?Sized,
+    Dst: TransmuteGuard<Src> + Pointee<Metadata = <Src as Pointee>::Metadata> + ?Sized,
+{
+    let (src_ptr, metadata) = core::ptr::from_ref(src).to_raw_parts();
+    unsafe { &*core::ptr::from_raw_parts(src_ptr, metadata) }
+}
+
+/// Same as [`safe_transmute_mut`](crate::safe_transmute_mut), except that `Src` and `Dst` can be
+/// any unsized types with the same pointer metadata, which is kept as is
+#[inline]
+pub const fn safe_transmute_mut_dst<Src, Dst>(src: &mut Src) -> &mut Dst
+where
+    Src: TransmuteGuard<Dst> + ?Sized,
+    Dst: TransmuteGuard<Src> + Pointee<Metadata = <Src as Pointee>::Metadata> + ?Sized,
+{
+    let (src_ptr, metadata) = core::ptr::from_mut(src).to_raw_parts();
+    unsafe { &mut *core::ptr::from_raw_parts_mut(src_ptr, metadata) }
+}
+
+/// Implements `TransmuteGuard<From>` and `SafeTransmuteRefFrom<From>` for the unsized type `To`,
+/// e.g. `impl_dst_transmute_guard! { unsafe [u8] => Bytes }` for
+/// `#[repr(transparent)] struct Bytes([u8])`.
+///
+/// `bidirectional A <=> B` implements both directions and `SafeTransmuteMutFrom` as well, where `A`
+/// must be a plain path or a slice.
+///
+/// Several impls can be separated by `;`, each with its own attributes like `#[cfg(...)]`.
+#[macro_export]
+macro_rules! impl_dst_transmute_guard {
+    {} => {};
+    {
+        $(#[$attr:meta])*
+        unsafe bidirectional [$A:ty] <=> $B:ty $(; $($rest:tt)*)?
+    } => {
+        $crate::impl_dst_transmute_guard! { @bidirectional [$(#[$attr])*] [$A] => $B }
+        $crate::impl_dst_transmute_guard! { $($($rest)*)? }
+    };
+    {
+        $(#[$attr:meta])*
+        unsafe bidirectional $($A:ident)::+ <=> $B:ty $(; $($rest:tt)*)?
+    } => {
+        $crate::impl_dst_transmute_guard! { @bidirectional [$(#[$attr])*] $($A)::+ => $B }
+        $crate::impl_dst_transmute_guard! { $($($rest)*)? }
+    };
+    { $(#[$attr:meta])* unsafe $From:ty => $To:ty $(; $($rest:tt)*)? } => {
+        $(#[$attr])*
+        unsafe impl $crate::TransmuteGuard<$From> for $To {}
+        $(#[$attr])*
+        unsafe impl $crate::SafeTransmuteRefFrom<$From> for $To {
+            #[inline]
+            fn safe_transmute_ref_from(value: &$From) -> &Self {
+                $crate::safe_transmute_ref_dst(value)
+            }
+        }
+        $crate::impl_dst_transmute_guard! { $($($rest)*)? }
+    };
+    { @bidirectional [$($attr:tt)*] $A:ty => $B:ty } => {
+        $crate::impl_dst_transmute_guard! { $($attr)* unsafe $A => $B; $($attr)* unsafe $B => $A }
+        $crate::impl_dst_transmute_guard! { @mut [$($attr)*] $A => $B }
+        $crate::impl_dst_transmute_guard! { @mut [$($attr)*] $B => $A }
+    };
+    { @mut [$($attr:tt)*] $From:ty => $To:ty } => {
+        $($attr)*
+        unsafe impl $crate::SafeTransmuteMutFrom<$From> for $To {
+            #[inline]
+            fn safe_transmute_mut_from(value: &mut $From) -> &mut Self {
+                $crate::safe_transmute_mut_dst(value)
+            }
+        }
+    };
+}
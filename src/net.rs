@@ -0,0 +1,37 @@
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4};
+
+use crate::Be;
+
+// The layouts of the address types are not guaranteed, so these go through their constructors
+// instead of transmute guards
+
+#[inline]
+pub const fn ipv4_from_bytes(bytes: [u8; 4]) -> Ipv4Addr {
+    Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3])
+}
+
+#[inline]
+pub const fn ipv4_to_bytes(addr: Ipv4Addr) -> [u8; 4] {
+    addr.octets()
+}
+
+#[inline]
+pub const fn ipv6_from_bytes(bytes: [u8; 16]) -> Ipv6Addr {
+    Ipv6Addr::from_bits(u128::from_be_bytes(bytes))
+}
+
+#[inline]
+pub const fn ipv6_to_bytes(addr: Ipv6Addr) -> [u8; 16] {
+    addr.octets()
+}
+
+/// The address and the port in network byte order, as they are laid out in packets
+#[inline]
+pub const fn socket_addr_v4_from_parts(ip: [u8; 4], port: Be<u16>) -> SocketAddrV4 {
+    SocketAddrV4::new(ipv4_from_bytes(ip), port.get())
+}
+
+#[inline]
+pub const fn socket_addr_v4_to_parts(addr: SocketAddrV4) -> ([u8; 4], Be<u16>) {
+    (ipv4_to_bytes(*addr.ip()), Be::<u16>::new(addr.port()))
+}
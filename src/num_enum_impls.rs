@@ -0,0 +1,45 @@
+/// Adds the transmute guard from enums implementing `num_enum::TryFromPrimitive` to their
+/// primitive and `TryTransmuteSliceFrom` of the primitive, validated with `try_from_primitive`,
+/// e.g. `num_enum_guard! { unsafe Status; }`.
+///
+/// Such enums are also supported as parents of [`enum_alias!`](crate::enum_alias), which reads the
+/// discriminants of the parent variants.
+///
+/// # Safety
+/// The enum must be fieldless with the primitive as its repr, and `try_from_primitive` must only
+/// accept discriminants. Deriving `TryFromPrimitive` without `catch_all` or `default` variants
+/// guarantees both.
+#[macro_export]
+macro_rules! num_enum_guard {
+    {} => {};
+    { unsafe $($name:ident)::+ $(; $($rest:tt)*)? } => {
+        $crate::impl_transmute_guard! {
+            unsafe $($name)::+
+                => <$($name)::+ as $crate::__private::num_enum::TryFromPrimitive>::Primitive
+        }
+
+        unsafe impl $crate::TryTransmuteSliceFrom<
+            <$($name)::+ as $crate::__private::num_enum::TryFromPrimitive>::Primitive
+        > for $($name)::+ {
+            #[inline]
+            fn validate_slice(
+                value: &[<Self as $crate::__private::num_enum::TryFromPrimitive>::Primitive],
+            ) -> ::core::result::Result<(), $crate::ValidityError> {
+                let invalid = value.iter().position(|value| {
+                    <Self as $crate::__private::num_enum::TryFromPrimitive>::try_from_primitive(
+                        *value,
+                    )
+                    .is_err()
+                });
+                match invalid {
+                    ::core::option::Option::Some(index) => {
+                        ::core::result::Result::Err($crate::ValidityError { index })
+                    }
+                    ::core::option::Option::None => ::core::result::Result::Ok(()),
+                }
+            }
+        }
+
+        $crate::num_enum_guard! { $($($rest)*)? }
+    };
+}
@@ -0,0 +1,50 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::ptr::NonNull;
+
+use crate::{transmute_unchecked, TransmuteGuard};
+
+// `None` is the null pointer for all of these, so they are transmuted as a whole without matching
+
+#[inline]
+pub const fn safe_transmute_option_ref<Src, Dst>(src: Option<&Src>) -> Option<&Dst>
+where
+    Dst: TransmuteGuard<Src>,
+{
+    unsafe { transmute_unchecked(src) }
+}
+
+#[inline]
+pub const fn safe_transmute_option_mut<Src, Dst>(src: Option<&mut Src>) -> Option<&mut Dst>
+where
+    Dst: TransmuteGuard<Src>,
+    Src: TransmuteGuard<Dst>,
+{
+    unsafe { transmute_unchecked(src) }
+}
+
+/// Fails to compile if `Src` and `Dst` have different sizes or alignments, because the allocation
+/// is freed with the layout of `Dst`
+#[cfg(feature = "alloc")]
+#[inline]
+pub const fn safe_transmute_option_box<Src, Dst>(src: Option<Box<Src>>) -> Option<Box<Dst>>
+where
+    Dst: TransmuteGuard<Src>,
+{
+    const {
+        assert!(core::mem::size_of::<Src>() == core::mem::size_of::<Dst>());
+        assert!(core::mem::align_of::<Src>() == core::mem::align_of::<Dst>());
+    }
+    unsafe { transmute_unchecked(src) }
+}
+
+/// Only changes the pointee type, dereferencing the pointer is as unsafe as before
+#[inline]
+pub const fn safe_transmute_option_non_null<Src, Dst>(
+    src: Option<NonNull<Src>>,
+) -> Option<NonNull<Dst>>
+where
+    Dst: TransmuteGuard<Src>,
+{
+    unsafe { transmute_unchecked(src) }
+}
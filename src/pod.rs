@@ -0,0 +1,311 @@
+use core::mem::{align_of, size_of, size_of_val};
+
+use crate::{AlignmentError, CastError, LengthError, SafeTransmuteFrom, TransmuteGuard, Zeroable};
+
+/// Marks types whose bytes can be read and written freely, for the byte views and casts in this
+/// module, which check sizes and alignments instead of needing transmute guards.
+///
+/// A blanket impl cannot name `[u8; size_of::<T>()]`, so the guard from a `Pod` type to its byte
+/// array comes from [`impl_pod_byte_guards!`](crate::impl_pod_byte_guards) or
+/// `#[derive(Packed)]`, and the integers and floats already have it, so `safe_transmute` and
+/// `safe_transmute_ref` turn them into `[u8; N]`. Only `u8` and `i8` have the guard back, any other
+/// `Pod` type is read from bytes with [`from_bytes`] or [`read_unaligned_transmute`].
+///
+/// # Safety
+/// Only implement this trait if every bit pattern is a valid `Self` and `Self` has no padding bytes
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not `Pod`",
+    label = "not every bit pattern is known to be a valid `{Self}`",
+    note = "derive `Packed` or write an `unsafe impl` if `{Self}` has no padding and accepts every \
+            bit pattern"
+)]
+pub unsafe trait Pod: Zeroable + Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            unsafe impl Pod for $ty {}
+
+            unsafe impl TransmuteGuard<$ty> for [u8; size_of::<$ty>()] {}
+            unsafe impl SafeTransmuteFrom<$ty> for [u8; size_of::<$ty>()] {
+                #[inline]
+                fn safe_transmute_from(value: $ty) -> Self {
+                    value.to_ne_bytes()
+                }
+            }
+        )*
+    };
+}
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+macro_rules! impl_from_byte {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            unsafe impl TransmuteGuard<[u8; 1]> for $ty {}
+            unsafe impl SafeTransmuteFrom<[u8; 1]> for $ty {
+                #[inline]
+                fn safe_transmute_from(value: [u8; 1]) -> Self {
+                    <$ty>::from_ne_bytes(value)
+                }
+            }
+        )*
+    };
+}
+impl_from_byte!(u8, i8);
+
+/// Implements the transmute guards from `Pod` types to their byte arrays, e.g.
+/// `impl_pod_byte_guards! { Rgb, Meters }`, which needs no `unsafe` since `Pod` already promises that
+/// every byte is initialized.
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Padded(u8, u16);
+/// transmute_guard::impl_pod_byte_guards! { Padded }
+/// ```
+#[macro_export]
+macro_rules! impl_pod_byte_guards {
+    { $($ty:ty),* $(,)? } => {$(
+        const _: () = {
+            #[allow(dead_code)]
+            fn pod() {
+                fn assert_pod<T: $crate::Pod>() {}
+                assert_pod::<$ty>();
+            }
+        };
+        $crate::impl_transmute_guard! { unsafe $ty => [u8; ::core::mem::size_of::<$ty>()] }
+    )*};
+}
+
+unsafe impl<T, const N: usize> Pod for [T; N] where T: Pod {}
+unsafe impl<T> Pod for core::num::Wrapping<T> where T: Pod {}
+unsafe impl<T> Pod for core::num::Saturating<T> where T: Pod {}
+
+#[inline]
+const fn cast_len<Src, Dst>(len: usize) -> Result<usize, CastError> {
+    match (size_of::<Src>(), size_of::<Dst>()) {
+        (src_size, dst_size) if src_size == dst_size => Ok(len),
+        (0, _) | (_, 0) => Err(CastError::SizeMismatch),
+        (src_size, dst_size) => {
+            let bytes = len * src_size;
+            if bytes.is_multiple_of(dst_size) {
+                Ok(bytes / dst_size)
+            } else {
+                Err(CastError::LengthMismatch(LengthError {
+                    len: bytes,
+                    size: dst_size,
+                }))
+            }
+        }
+    }
+}
+
+#[inline]
+fn check_align<Src, Dst>(src_ptr: *const Src) -> Result<(), CastError> {
+    if align_of::<Dst>() > align_of::<Src>() && !src_ptr.cast::<Dst>().is_aligned() {
+        Err(CastError::Misaligned(AlignmentError {
+            align: align_of::<Dst>(),
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+pub fn try_cast_slice<Src, Dst>(src: &[Src]) -> Result<&[Dst], CastError>
+where
+    Src: Pod,
+    Dst: Pod,
+{
+    let len = cast_len::<Src, Dst>(src.len())?;
+    check_align::<Src, Dst>(src.as_ptr())?;
+    Ok(unsafe { core::slice::from_raw_parts(src.as_ptr().cast(), len) })
+}
+
+#[inline]
+pub fn try_cast_slice_mut<Src, Dst>(src: &mut [Src]) -> Result<&mut [Dst], CastError>
+where
+    Src: Pod,
+    Dst: Pod,
+{
+    let len = cast_len::<Src, Dst>(src.len())?;
+    check_align::<Src, Dst>(src.as_ptr())?;
+    Ok(unsafe { core::slice::from_raw_parts_mut(src.as_mut_ptr().cast(), len) })
+}
+
+#[inline]
+pub fn transmute_prefix_suffix<T>(src: &[u8]) -> (&[u8], &[T], &[u8])
+where
+    T: Pod,
+{
+    unsafe { src.align_to::<T>() }
+}
+
+#[inline]
+pub fn transmute_prefix_suffix_mut<T>(src: &mut [u8]) -> (&mut [u8], &mut [T], &mut [u8])
+where
+    T: Pod,
+{
+    unsafe { src.align_to_mut::<T>() }
+}
+
+#[inline]
+pub fn split_transmute_front<T>(src: &[u8]) -> Result<(&T, &[u8]), CastError>
+where
+    T: Pod,
+{
+    if src.len() < size_of::<T>() {
+        return Err(CastError::OutOfBounds(LengthError {
+            len: src.len(),
+            size: size_of::<T>(),
+        }));
+    }
+    check_align::<u8, T>(src.as_ptr())?;
+    let (head, tail) = src.split_at(size_of::<T>());
+    Ok((unsafe { &*head.as_ptr().cast() }, tail))
+}
+
+#[inline]
+pub fn split_transmute_front_mut<T>(src: &mut [u8]) -> Result<(&mut T, &mut [u8]), CastError>
+where
+    T: Pod,
+{
+    if src.len() < size_of::<T>() {
+        return Err(CastError::OutOfBounds(LengthError {
+            len: src.len(),
+            size: size_of::<T>(),
+        }));
+    }
+    check_align::<u8, T>(src.as_ptr())?;
+    let (head, tail) = src.split_at_mut(size_of::<T>());
+    Ok((unsafe { &mut *head.as_mut_ptr().cast() }, tail))
+}
+
+#[inline]
+fn offset_range<T>(len: usize, offset: usize) -> Result<core::ops::Range<usize>, CastError> {
+    match offset.checked_add(size_of::<T>()) {
+        Some(end) if end <= len => Ok(offset..end),
+        _ => Err(CastError::OutOfBounds(LengthError {
+            len,
+            size: offset.saturating_add(size_of::<T>()),
+        })),
+    }
+}
+
+/// The `T` at `offset` bytes into `src`, which fails if it does not fit or is misaligned
+#[inline]
+pub fn get_transmute<T>(src: &[u8], offset: usize) -> Result<&T, CastError>
+where
+    T: Pod,
+{
+    let bytes = &src[offset_range::<T>(src.len(), offset)?];
+    check_align::<u8, T>(bytes.as_ptr())?;
+    Ok(unsafe { &*bytes.as_ptr().cast() })
+}
+
+#[inline]
+pub fn get_transmute_mut<T>(src: &mut [u8], offset: usize) -> Result<&mut T, CastError>
+where
+    T: Pod,
+{
+    let range = offset_range::<T>(src.len(), offset)?;
+    let bytes = &mut src[range];
+    check_align::<u8, T>(bytes.as_ptr())?;
+    Ok(unsafe { &mut *bytes.as_mut_ptr().cast() })
+}
+
+/// Copies the bytes of `value` to `offset` bytes into `dst`, which only fails if they do not fit,
+/// since copying bytes does not need any alignment
+#[inline]
+pub fn put_transmute<T>(dst: &mut [u8], offset: usize, value: &T) -> Result<(), CastError>
+where
+    T: Pod,
+{
+    let range = offset_range::<T>(dst.len(), offset)?;
+    dst[range].copy_from_slice(bytes_of(value));
+    Ok(())
+}
+
+#[inline]
+pub const fn bytes_of<T>(value: &T) -> &[u8]
+where
+    T: Pod,
+{
+    unsafe { core::slice::from_raw_parts(core::ptr::from_ref(value).cast(), size_of::<T>()) }
+}
+
+#[inline]
+pub(crate) fn bytes_of_slice<T>(values: &[T]) -> &[u8]
+where
+    T: Pod,
+{
+    unsafe { core::slice::from_raw_parts(values.as_ptr().cast(), size_of_val(values)) }
+}
+
+#[inline]
+pub fn bytes_of_mut<T>(value: &mut T) -> &mut [u8]
+where
+    T: Pod,
+{
+    unsafe { core::slice::from_raw_parts_mut(core::ptr::from_mut(value).cast(), size_of::<T>()) }
+}
+
+/// Fails to compile if `Src` and `Dst` have different sizes or `Dst` is more aligned than `Src`
+#[inline]
+pub fn cast_mut<Src, Dst>(src: &mut Src) -> &mut Dst
+where
+    Src: Pod,
+    Dst: Pod,
+{
+    const {
+        assert!(size_of::<Src>() == size_of::<Dst>());
+        assert!(align_of::<Src>() >= align_of::<Dst>());
+    }
+    unsafe { &mut *core::ptr::from_mut(src).cast() }
+}
+
+/// # Panics
+/// Panics if the length of `bytes` is not the size of `T` or `bytes` is not aligned for `T`
+#[inline]
+pub fn from_bytes<T>(bytes: &[u8]) -> &T
+where
+    T: Pod,
+{
+    assert!(
+        bytes.len() == size_of::<T>(),
+        "length of the bytes is not the size of the type"
+    );
+    assert!(
+        bytes.as_ptr().cast::<T>().is_aligned(),
+        "bytes are not aligned for the type"
+    );
+    unsafe { &*bytes.as_ptr().cast() }
+}
+
+/// Same as [`from_bytes`], except that the value is copied out so `bytes` can have any alignment
+///
+/// # Panics
+/// Panics if the length of `bytes` is not the size of `T`
+#[inline]
+pub fn read_unaligned_transmute<T>(bytes: &[u8]) -> T
+where
+    T: Pod,
+{
+    assert!(
+        bytes.len() == size_of::<T>(),
+        "length of the bytes is not the size of the type"
+    );
+    unsafe { bytes.as_ptr().cast::<T>().read_unaligned() }
+}
+
+/// Same as [`read_unaligned_transmute`], except that the size is checked at compile time
+#[inline]
+pub const fn read_unaligned_array<T, const N: usize>(bytes: &[u8; N]) -> T
+where
+    T: Pod,
+{
+    const {
+        assert!(N == size_of::<T>());
+    }
+    unsafe { core::ptr::from_ref(bytes).cast::<T>().read_unaligned() }
+}
@@ -0,0 +1,74 @@
+use crate::transmute_unchecked;
+
+/// A view of the first field of a struct, which is separate from [`SafeTransmuteRefFrom`] and
+/// [`SafeTransmuteMutFrom`](crate::SafeTransmuteMutFrom), because those require a
+/// [`TransmuteGuard`](crate::TransmuteGuard), which also allows slices and values to be transmuted
+/// and so needs `Field` to have the size of `Self`.
+///
+/// # Safety
+/// Only implement this trait if `Field` is at offset 0 of `Self`
+///
+/// [`SafeTransmuteRefFrom`]: crate::SafeTransmuteRefFrom
+pub unsafe trait FirstField<Field> {
+    #[inline]
+    fn first_field(&self) -> &Field {
+        unsafe { transmute_unchecked(core::ptr::from_ref(self).cast::<Field>()) }
+    }
+
+    #[inline]
+    fn first_field_mut(&mut self) -> &mut Field {
+        unsafe { transmute_unchecked(core::ptr::from_mut(self).cast::<Field>()) }
+    }
+}
+
+/// Implements `FirstField` after checking that the field is at offset 0 and has exactly the type
+/// `Field`, e.g. `project_first_field! { Node => link: Link }`.
+///
+/// A field that only derefs to `Field` is rejected:
+/// ```compile_fail
+/// struct Node {
+///     link: Box<[u64; 16]>,
+/// }
+/// transmute_guard::project_first_field! { Node => link: [u64; 16] }
+/// ```
+#[macro_export]
+macro_rules! project_first_field {
+    { $($Struct:ty => $field:tt: $Field:ty);* $(;)? } => {$(
+        const _: () = {
+            #[allow(dead_code)]
+            fn field_type(value: &$Struct) {
+                $crate::__private::assert_same_type::<$Field, _>(&value.$field);
+            }
+
+            ::core::assert!(
+                ::core::mem::offset_of!($Struct, $field) == 0,
+                ::core::concat!(
+                    "`",
+                    ::core::stringify!($field),
+                    "` is not the first field of `",
+                    ::core::stringify!($Struct),
+                    "`",
+                ),
+            );
+        };
+        unsafe impl $crate::FirstField<$Field> for $Struct {}
+    )*};
+}
+
+#[doc(hidden)]
+pub trait SameType<T>
+where
+    T: ?Sized,
+{
+}
+impl<T> SameType<T> for T where T: ?Sized {}
+
+/// `U` is inferred from the argument, so it is the exact type of the field and never a coercion
+#[doc(hidden)]
+#[inline]
+pub const fn assert_same_type<T, U>(_: &U)
+where
+    T: ?Sized,
+    U: SameType<T> + ?Sized,
+{
+}
@@ -0,0 +1,86 @@
+use core::mem::size_of;
+
+use crate::{try_cast_slice, CastError, LengthError, Pod};
+
+/// Reads `Pod` values from a byte buffer without copying, advancing past every value read.
+///
+/// A failed read leaves the position unchanged.
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Number of bytes read or skipped so far
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    #[inline]
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    #[inline]
+    fn peek_bytes(&self, len: usize) -> Result<&'a [u8], CastError> {
+        let remaining = self.remaining();
+        remaining
+            .get(..len)
+            .ok_or(CastError::OutOfBounds(LengthError {
+                len: remaining.len(),
+                size: len,
+            }))
+    }
+
+    #[inline]
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], CastError> {
+        let bytes = self.peek_bytes(len)?;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    #[inline]
+    pub fn skip(&mut self, len: usize) -> Result<(), CastError> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    #[inline]
+    pub fn read<T>(&mut self) -> Result<&'a T, CastError>
+    where
+        T: Pod,
+    {
+        self.read_slice::<T>(1).map(|slice| &slice[0])
+    }
+
+    #[inline]
+    pub fn read_array<T, const N: usize>(&mut self) -> Result<&'a [T; N], CastError>
+    where
+        T: Pod,
+    {
+        self.read::<[T; N]>()
+    }
+
+    #[inline]
+    pub fn read_slice<T>(&mut self, len: usize) -> Result<&'a [T], CastError>
+    where
+        T: Pod,
+    {
+        let size = len.saturating_mul(size_of::<T>());
+        let bytes = self.peek_bytes(size)?;
+        let slice = try_cast_slice(bytes)?;
+        self.position += size;
+        Ok(slice)
+    }
+}
@@ -0,0 +1,45 @@
+use core::mem::size_of;
+
+use rkyv::rend::{self, unaligned};
+
+use crate::{Be, Le, Pod, Zeroable};
+
+// The aligned archived numbers only convert one way, because they can be more aligned than `Le`
+// and `Be`, and `Pod` makes them available to the checked slice casts over archive buffers
+macro_rules! impl_rend {
+    ($($prim:ident: $le:ident $be:ident $ule:ident $ube:ident;)*) => {
+        $(
+            crate::impl_transmute_guard! {
+                unsafe bidirectional unaligned::$ule <=> Le<$prim>;
+                unsafe bidirectional unaligned::$ube <=> Be<$prim>;
+                unsafe bidirectional unaligned::$ule <=> [u8; size_of::<$prim>()];
+                unsafe bidirectional unaligned::$ube <=> [u8; size_of::<$prim>()];
+                unsafe rend::$le => Le<$prim>;
+                unsafe rend::$be => Be<$prim>;
+                unsafe rend::$le => [u8; size_of::<$prim>()];
+                unsafe rend::$be => [u8; size_of::<$prim>()];
+            }
+            unsafe impl Zeroable for rend::$le {}
+            unsafe impl Zeroable for rend::$be {}
+            unsafe impl Zeroable for unaligned::$ule {}
+            unsafe impl Zeroable for unaligned::$ube {}
+            unsafe impl Pod for rend::$le {}
+            unsafe impl Pod for rend::$be {}
+            unsafe impl Pod for unaligned::$ule {}
+            unsafe impl Pod for unaligned::$ube {}
+        )*
+    };
+}
+
+impl_rend! {
+    u16: u16_le u16_be u16_ule u16_ube;
+    u32: u32_le u32_be u32_ule u32_ube;
+    u64: u64_le u64_be u64_ule u64_ube;
+    u128: u128_le u128_be u128_ule u128_ube;
+    i16: i16_le i16_be i16_ule i16_ube;
+    i32: i32_le i32_be i32_ule i32_ube;
+    i64: i64_le i64_be i64_ule i64_ube;
+    i128: i128_le i128_be i128_ule i128_ube;
+    f32: f32_le f32_be f32_ule f32_ube;
+    f64: f64_le f64_be f64_ule f64_ube;
+}
@@ -0,0 +1,64 @@
+use core::any::type_name;
+use core::mem::{align_of_val, size_of_val};
+
+use crate::{bytes_of, Pod, SafeTransmuteFrom, SafeTransmuteRefFrom};
+
+/// Transmutes every sample from `A` to `B` and back, e.g. to test an `unsafe impl` of the guard
+/// traits with generated samples
+///
+/// # Panics
+/// Panics at the first sample whose bytes changed on the way
+#[track_caller]
+pub fn check_roundtrip<A, B>(samples: impl IntoIterator<Item = A>)
+where
+    A: Pod + SafeTransmuteFrom<B>,
+    B: SafeTransmuteFrom<A>,
+{
+    for (index, sample) in samples.into_iter().enumerate() {
+        let back = A::safe_transmute_from(B::safe_transmute_from(sample));
+        assert!(
+            bytes_of(&sample) == bytes_of(&back),
+            "sample {index} changed when transmuted from `{}` to `{}` and back",
+            type_name::<A>(),
+            type_name::<B>(),
+        );
+    }
+}
+
+/// Transmutes every sample reference from `&A` to `&B` and back, checking that both references
+/// point to the same bytes
+///
+/// # Panics
+/// Panics at the first sample whose address or size changed, or whose `&B` is misaligned
+#[track_caller]
+pub fn check_ref_roundtrip<'a, A, B>(samples: impl IntoIterator<Item = &'a A>)
+where
+    A: SafeTransmuteRefFrom<B> + ?Sized + 'a,
+    B: SafeTransmuteRefFrom<A> + ?Sized,
+{
+    for (index, sample) in samples.into_iter().enumerate() {
+        let dst = B::safe_transmute_ref_from(sample);
+        let back = A::safe_transmute_ref_from(dst);
+        assert!(
+            core::ptr::addr_eq(sample, dst) && core::ptr::addr_eq(sample, back),
+            "sample {index} moved when transmuted from `&{}` to `&{}` and back",
+            type_name::<A>(),
+            type_name::<B>(),
+        );
+        assert!(
+            size_of_val(sample) == size_of_val(dst) && size_of_val(sample) == size_of_val(back),
+            "sample {index} changed size when transmuted from `&{}` to `&{}` and back",
+            type_name::<A>(),
+            type_name::<B>(),
+        );
+        assert!(
+            core::ptr::from_ref(dst)
+                .cast::<u8>()
+                .addr()
+                .is_multiple_of(align_of_val(dst)),
+            "sample {index} is misaligned when transmuted from `&{}` to `&{}`",
+            type_name::<A>(),
+            type_name::<B>(),
+        );
+    }
+}
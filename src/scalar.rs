@@ -0,0 +1,90 @@
+/// Declares `#[repr(C)]` structs whose fields all have the same type, with the transmute guards in
+/// both directions with an array of the fields and methods that view slices of the fields as
+/// slices of the struct and back, e.g.
+/// `scalar_struct! { pub struct Vec3 { pub x: f32, pub y: f32, pub z: f32 } }`.
+///
+/// `try_slice_from_scalars` fails with [`CastError::LengthMismatch`](crate::CastError) if the
+/// length is not a multiple of the number of fields.
+///
+/// The fields must have exactly the same type, so a type that only coerces to the first one is
+/// rejected:
+/// ```compile_fail
+/// type Shared = &'static u8;
+/// type Unique = &'static mut u8;
+/// transmute_guard::scalar_struct! { pub struct Refs { pub shared: Shared, pub unique: Unique } }
+/// ```
+#[macro_export]
+macro_rules! scalar_struct {
+    {} => {};
+    {
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(#[$first_meta:meta])* $first_vis:vis $first:ident: $ty:ty
+            $(, $(#[$field_meta:meta])* $field_vis:vis $field:ident: $field_ty:ty)* $(,)?
+        }
+        $($rest:tt)*
+    } => {
+        $(#[$meta])*
+        #[repr(C)]
+        $vis struct $name {
+            $(#[$first_meta])* $first_vis $first: $ty,
+            $($(#[$field_meta])* $field_vis $field: $field_ty,)*
+        }
+
+        // Every field must have exactly the type of the first one, so there is no padding between
+        // them
+        const _: () = {
+            #[allow(dead_code)]
+            fn same_type(value: &$name) {
+                $($crate::__private::assert_same_type::<$ty, _>(&value.$field);)*
+            }
+        };
+
+        $crate::impl_transmute_guard! { unsafe bidirectional $name <=> [$ty; <$name>::SCALARS] }
+
+        impl $name {
+            /// Number of fields
+            $vis const SCALARS: usize = 1 $(+ { _ = ::core::stringify!($field); 1 })*;
+
+            #[inline]
+            $vis const fn slice_as_scalars(slice: &[Self]) -> &[$ty] {
+                $crate::flatten_slice::<$ty, { <$name>::SCALARS }>(
+                    $crate::safe_transmute_slice(slice),
+                )
+            }
+
+            #[inline]
+            $vis const fn slice_as_scalars_mut(slice: &mut [Self]) -> &mut [$ty] {
+                $crate::flatten_slice_mut::<$ty, { <$name>::SCALARS }>(
+                    $crate::safe_transmute_slice_mut(slice),
+                )
+            }
+
+            #[inline]
+            $vis const fn try_slice_from_scalars<'a>(
+                scalars: &'a [$ty],
+            ) -> ::core::result::Result<&'a [Self], $crate::CastError> {
+                match $crate::try_unflatten_slice::<$ty, { <$name>::SCALARS }>(scalars) {
+                    ::core::result::Result::Ok(arrays) => {
+                        ::core::result::Result::Ok($crate::safe_transmute_slice(arrays))
+                    }
+                    ::core::result::Result::Err(error) => ::core::result::Result::Err(error),
+                }
+            }
+
+            #[inline]
+            $vis const fn try_slice_from_scalars_mut<'a>(
+                scalars: &'a mut [$ty],
+            ) -> ::core::result::Result<&'a mut [Self], $crate::CastError> {
+                match $crate::try_unflatten_slice_mut::<$ty, { <$name>::SCALARS }>(scalars) {
+                    ::core::result::Result::Ok(arrays) => {
+                        ::core::result::Result::Ok($crate::safe_transmute_slice_mut(arrays))
+                    }
+                    ::core::result::Result::Err(error) => ::core::result::Result::Err(error),
+                }
+            }
+        }
+
+        $crate::scalar_struct! { $($rest)* }
+    };
+}
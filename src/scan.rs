@@ -0,0 +1,52 @@
+const WORD_BYTES: usize = core::mem::size_of::<usize>();
+
+#[inline]
+fn find_masked_byte_scalar(bytes: &[u8], mask: u8) -> Option<usize> {
+    bytes.iter().position(|&byte| byte & mask != 0)
+}
+
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn find_masked_byte_aligned(bytes: &[u8], mask: u8) -> Option<usize> {
+    let word_mask = usize::from_ne_bytes([mask; WORD_BYTES]);
+    let (prefix, words, _) = unsafe { bytes.align_to::<usize>() };
+    if let Some(index) = find_masked_byte_scalar(prefix, mask) {
+        return Some(index);
+    }
+    let mut offset = prefix.len();
+    for word in words {
+        if word & word_mask != 0 {
+            break;
+        }
+        offset += WORD_BYTES;
+    }
+    find_masked_byte_scalar(&bytes[offset..], mask).map(|index| index + offset)
+}
+
+#[cfg(feature = "simd")]
+#[inline]
+fn find_masked_byte_aligned(bytes: &[u8], mask: u8) -> Option<usize> {
+    use core::simd::{cmp::SimdPartialEq, Simd};
+
+    const LANES: usize = 4 * WORD_BYTES;
+    let simd_mask = Simd::<u8, LANES>::splat(mask);
+    let zero = Simd::<u8, LANES>::splat(0);
+    let mut offset = 0;
+    for chunk in bytes.chunks_exact(LANES) {
+        if (Simd::from_slice(chunk) & simd_mask).simd_ne(zero).any() {
+            break;
+        }
+        offset += LANES;
+    }
+    find_masked_byte_scalar(&bytes[offset..], mask).map(|index| index + offset)
+}
+
+/// Returns the index of the first byte that has any of the bits in `mask` set
+#[inline]
+pub(crate) fn find_masked_byte(bytes: &[u8], mask: u8) -> Option<usize> {
+    if bytes.len() < 2 * WORD_BYTES {
+        find_masked_byte_scalar(bytes, mask)
+    } else {
+        find_masked_byte_aligned(bytes, mask)
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_alias_serde {
+    { $name:ident: $ty:ty } => {
+        impl $crate::__private::serde::Serialize for $name {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&self.as_parent(), serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            #[inline]
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let parent =
+                    <$ty as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                Self::try_from_parent(parent)
+                    .map_err(<D::Error as $crate::__private::serde::de::Error>::custom)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enum_alias_serde {
+    { $($alias:tt)* } => {
+        ::core::compile_error!(
+            "the `serde` option of `enum_alias!` requires the `serde` feature of `transmute_guard`"
+        );
+    };
+}
+
+/// Implements `Serialize` and `Deserialize` for wrappers as their inner type through the transmute
+/// guards, e.g. `serde_as_inner! { Meters => f32; }`.
+///
+/// Requires the `serde` feature and the guards in both directions.
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! serde_as_inner {
+    {} => {};
+    { $(#[$attr:meta])* $name:ty => $inner:ty $(; $($rest:tt)*)? } => {
+        $(#[$attr])*
+        impl $crate::__private::serde::Serialize for $name {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                let inner: &$inner = $crate::safe_transmute_ref(self);
+                $crate::__private::serde::Serialize::serialize(inner, serializer)
+            }
+        }
+
+        $(#[$attr])*
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            #[inline]
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let inner: $inner =
+                    $crate::__private::serde::Deserialize::deserialize(deserializer)?;
+                ::core::result::Result::Ok($crate::safe_transmute(inner))
+            }
+        }
+
+        $crate::serde_as_inner! { $($($rest)*)? }
+    };
+}
+
+/// Implements `Serialize` and `Deserialize` for wrappers as their inner type through the transmute
+/// guards, e.g. `serde_as_inner! { Meters => f32; }`.
+///
+/// Requires the `serde` feature and the guards in both directions.
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! serde_as_inner {
+    { $($input:tt)* } => {
+        ::core::compile_error!(
+            "`serde_as_inner!` requires the `serde` feature of `transmute_guard`"
+        );
+    };
+}
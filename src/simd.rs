@@ -0,0 +1,108 @@
+use core::mem::{align_of, size_of, size_of_val};
+use core::simd::{Simd, SimdElement};
+
+use crate::{
+    AlignmentError, CastError, LengthError, SafeTransmuteFrom, SafeTransmuteMutFrom, TransmuteGuard,
+};
+
+// There is no guard from `[T; N]` to `Simd<T, N>`, because it would also allow ref transmutes and
+// the vector can be more aligned, so that direction is `transmute_array_to_simd` by value
+unsafe impl<T, const N: usize> TransmuteGuard<Simd<T, N>> for [T; N] where T: SimdElement {}
+unsafe impl<T, const N: usize> SafeTransmuteFrom<Simd<T, N>> for [T; N]
+where
+    T: SimdElement,
+{
+    #[inline]
+    fn safe_transmute_from(value: Simd<T, N>) -> Self {
+        value.to_array()
+    }
+}
+unsafe impl<T, const N: usize> SafeTransmuteMutFrom<Simd<T, N>> for [T; N]
+where
+    T: SimdElement,
+{
+    #[inline]
+    fn safe_transmute_mut_from(value: &mut Simd<T, N>) -> &mut Self {
+        value.as_mut_array()
+    }
+}
+
+/// Moves `array` into a vector, which is the by-value counterpart of the guard from `Simd<T, N>` to
+/// `[T; N]`
+#[inline]
+pub const fn transmute_array_to_simd<T, const N: usize>(array: [T; N]) -> Simd<T, N>
+where
+    T: SimdElement,
+{
+    Simd::from_array(array)
+}
+
+#[inline]
+const fn assert_simd_size<T, const N: usize>()
+where
+    T: SimdElement,
+{
+    const {
+        assert!(size_of::<Simd<T, N>>() == N * size_of::<T>());
+    }
+}
+
+#[inline]
+pub const fn flatten_simd_slice<T, const N: usize>(src: &[Simd<T, N>]) -> &[T]
+where
+    T: SimdElement,
+{
+    assert_simd_size::<T, N>();
+    unsafe { core::slice::from_raw_parts(src.as_ptr().cast(), src.len() * N) }
+}
+
+#[inline]
+pub const fn flatten_simd_slice_mut<T, const N: usize>(src: &mut [Simd<T, N>]) -> &mut [T]
+where
+    T: SimdElement,
+{
+    assert_simd_size::<T, N>();
+    unsafe { core::slice::from_raw_parts_mut(src.as_mut_ptr().cast(), src.len() * N) }
+}
+
+#[inline]
+fn check_unflatten<T, const N: usize>(src: &[T]) -> Result<usize, CastError>
+where
+    T: SimdElement,
+{
+    assert_simd_size::<T, N>();
+    if !src.len().is_multiple_of(N) {
+        Err(CastError::LengthMismatch(LengthError {
+            len: size_of_val(src),
+            size: size_of::<Simd<T, N>>(),
+        }))
+    } else if align_of::<Simd<T, N>>() > align_of::<T>()
+        && !src.as_ptr().cast::<Simd<T, N>>().is_aligned()
+    {
+        Err(CastError::Misaligned(AlignmentError {
+            align: align_of::<Simd<T, N>>(),
+        }))
+    } else {
+        Ok(src.len() / N)
+    }
+}
+
+#[inline]
+pub fn try_unflatten_simd_slice<T, const N: usize>(src: &[T]) -> Result<&[Simd<T, N>], CastError>
+where
+    T: SimdElement,
+{
+    let len = check_unflatten::<T, N>(src)?;
+    Ok(unsafe { core::slice::from_raw_parts(src.as_ptr().cast(), len) })
+}
+
+#[inline]
+pub fn try_unflatten_simd_slice_mut<T, const N: usize>(
+    src: &mut [T],
+) -> Result<&mut [Simd<T, N>], CastError>
+where
+    T: SimdElement,
+{
+    let len = check_unflatten::<T, N>(src)?;
+    Ok(unsafe { core::slice::from_raw_parts_mut(src.as_mut_ptr().cast(), len) })
+}
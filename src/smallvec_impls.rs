@@ -0,0 +1,31 @@
+use alloc::vec::Vec;
+use core::mem::{align_of, ManuallyDrop};
+
+use smallvec::SmallVec;
+
+use crate::{safe_transmute, specialize::try_identity, SafeTransmuteFrom};
+
+/// Transmutes every element of `src` and keeps the inline capacity
+///
+/// A spilled `src` keeps its heap allocation if `Src` and `Dst` have the same alignment, otherwise
+/// the elements are copied.
+#[inline]
+pub fn safe_transmute_small_vec<Src, Dst, const N: usize>(
+    src: SmallVec<[Src; N]>,
+) -> SmallVec<[Dst; N]>
+where
+    Dst: SafeTransmuteFrom<Src>,
+{
+    let src = match try_identity(src) {
+        Ok(dst) => return dst,
+        Err(src) => src,
+    };
+    if align_of::<Src>() == align_of::<Dst>() && src.spilled() {
+        let mut vec = ManuallyDrop::new(src.into_vec());
+        let (ptr, len, capacity) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+        // the capacity is still more than `N`, so `from_vec` does not move it inline
+        SmallVec::from_vec(unsafe { Vec::from_raw_parts(ptr.cast(), len, capacity) })
+    } else {
+        src.into_iter().map(safe_transmute).collect()
+    }
+}
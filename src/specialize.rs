@@ -0,0 +1,29 @@
+// Only the identity is detected, so conversions between different types still go through
+// `SafeTransmuteFrom` and keep their audits
+
+#[cfg(feature = "specialization")]
+trait IsIdentity<Src> {
+    const IS_IDENTITY: bool;
+}
+
+#[cfg(feature = "specialization")]
+impl<Src, Dst> IsIdentity<Src> for Dst {
+    default const IS_IDENTITY: bool = false;
+}
+
+#[cfg(feature = "specialization")]
+impl<T> IsIdentity<T> for T {
+    const IS_IDENTITY: bool = true;
+}
+
+/// `src` as a `Dst` if they are the same type, which is never detected without the
+/// `specialization` feature
+#[inline(always)]
+pub(crate) fn try_identity<Src, Dst>(src: Src) -> Result<Dst, Src> {
+    #[cfg(feature = "specialization")]
+    if <Dst as IsIdentity<Src>>::IS_IDENTITY {
+        let src = core::mem::ManuallyDrop::new(src);
+        return Ok(unsafe { core::ptr::read(core::ptr::from_ref(&*src).cast()) });
+    }
+    Err(src)
+}
@@ -0,0 +1,250 @@
+//! String slices in other encodings that can be viewed as bytes without copying
+
+use core::fmt;
+
+use crate::{
+    checked::validate_bytes, safe_transmute_mut, safe_transmute_ref, SafeTransmuteMutFrom,
+    SafeTransmuteRefFrom, ValidityError,
+};
+
+/// A string slice in Latin-1 (ISO 8859-1), where every byte is the code point of its char
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Latin1Str([u8]);
+
+/// A byte slice that has been checked to be UTF-8
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Utf8Bytes([u8]);
+
+crate::impl_transmute_guard! {
+    unsafe ?Sized [u8] => Latin1Str;
+    unsafe ?Sized Latin1Str => [u8];
+    unsafe ?Sized Utf8Bytes => [u8];
+    unsafe ?Sized Utf8Bytes => str;
+    unsafe ?Sized str => Utf8Bytes;
+}
+
+unsafe impl SafeTransmuteRefFrom<[u8]> for Latin1Str {
+    #[inline]
+    fn safe_transmute_ref_from(value: &[u8]) -> &Self {
+        let from_ptr: *const [u8] = core::ptr::from_ref(value);
+        unsafe { &*(from_ptr as *const Self) }
+    }
+}
+
+unsafe impl SafeTransmuteMutFrom<[u8]> for Latin1Str {
+    #[inline]
+    fn safe_transmute_mut_from(value: &mut [u8]) -> &mut Self {
+        let from_ptr: *mut [u8] = core::ptr::from_mut(value);
+        unsafe { &mut *(from_ptr as *mut Self) }
+    }
+}
+
+unsafe impl SafeTransmuteRefFrom<Latin1Str> for [u8] {
+    #[inline]
+    fn safe_transmute_ref_from(value: &Latin1Str) -> &Self {
+        &value.0
+    }
+}
+
+unsafe impl SafeTransmuteMutFrom<Latin1Str> for [u8] {
+    #[inline]
+    fn safe_transmute_mut_from(value: &mut Latin1Str) -> &mut Self {
+        &mut value.0
+    }
+}
+
+unsafe impl SafeTransmuteRefFrom<str> for Utf8Bytes {
+    #[inline]
+    fn safe_transmute_ref_from(value: &str) -> &Self {
+        let from_ptr: *const [u8] = core::ptr::from_ref(value.as_bytes());
+        unsafe { &*(from_ptr as *const Self) }
+    }
+}
+
+unsafe impl SafeTransmuteMutFrom<str> for Utf8Bytes {
+    #[inline]
+    fn safe_transmute_mut_from(value: &mut str) -> &mut Self {
+        let from_ptr: *mut [u8] = core::ptr::from_mut(unsafe { value.as_bytes_mut() });
+        unsafe { &mut *(from_ptr as *mut Self) }
+    }
+}
+
+unsafe impl SafeTransmuteRefFrom<Utf8Bytes> for [u8] {
+    #[inline]
+    fn safe_transmute_ref_from(value: &Utf8Bytes) -> &Self {
+        &value.0
+    }
+}
+
+unsafe impl SafeTransmuteRefFrom<Utf8Bytes> for str {
+    #[inline]
+    fn safe_transmute_ref_from(value: &Utf8Bytes) -> &Self {
+        unsafe { core::str::from_utf8_unchecked(&value.0) }
+    }
+}
+
+unsafe impl SafeTransmuteMutFrom<Utf8Bytes> for str {
+    #[inline]
+    fn safe_transmute_mut_from(value: &mut Utf8Bytes) -> &mut Self {
+        unsafe { core::str::from_utf8_unchecked_mut(&mut value.0) }
+    }
+}
+
+impl Latin1Str {
+    #[inline]
+    pub fn new(bytes: &[u8]) -> &Self {
+        safe_transmute_ref(bytes)
+    }
+
+    #[inline]
+    pub fn new_mut(bytes: &mut [u8]) -> &mut Self {
+        safe_transmute_mut(bytes)
+    }
+
+    /// Views `str` as Latin-1 if it is ASCII, the only chars with the same bytes in both
+    #[inline]
+    pub fn try_from_str(str: &str) -> Result<&Self, ValidityError> {
+        validate_bytes(str.as_bytes(), 0x80)?;
+        Ok(Self::new(str.as_bytes()))
+    }
+
+    /// Views `self` as a `str` if it is ASCII, the only chars with the same bytes in both
+    #[inline]
+    pub fn try_as_str(&self) -> Result<&str, ValidityError> {
+        crate::try_transmute_ascii_str(&self.0)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn chars(&self) -> impl DoubleEndedIterator<Item = char> + ExactSizeIterator + '_ {
+        self.0.iter().map(|byte| char::from(*byte))
+    }
+}
+
+impl fmt::Debug for Latin1Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for char in self.chars() {
+            fmt::Display::fmt(&char.escape_debug(), f)?;
+        }
+        f.write_str("\"")
+    }
+}
+
+impl fmt::Display for Latin1Str {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars()
+            .try_for_each(|char| fmt::Write::write_char(f, char))
+    }
+}
+
+impl Utf8Bytes {
+    #[inline]
+    pub fn new(bytes: &[u8]) -> Result<&Self, ValidityError> {
+        match core::str::from_utf8(bytes) {
+            Ok(str) => Ok(Self::from_utf8_str(str)),
+            Err(error) => Err(ValidityError {
+                index: error.valid_up_to(),
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn from_utf8_str(str: &str) -> &Self {
+        safe_transmute_ref(str)
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        safe_transmute_ref(self)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Utf8Bytes {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for Utf8Bytes {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for Latin1Str {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl core::hash::Hash for Utf8Bytes {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::hash::Hash::hash(self.as_str(), state);
+    }
+}
+
+impl core::borrow::Borrow<str> for Utf8Bytes {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::borrow::Borrow<str> for &Utf8Bytes {
+    #[inline]
+    fn borrow(&self) -> &str {
+        (*self).as_str()
+    }
+}
+
+impl core::borrow::Borrow<[u8]> for Latin1Str {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl core::borrow::Borrow<[u8]> for &Latin1Str {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        (*self).as_bytes()
+    }
+}
+
+impl fmt::Debug for Utf8Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Utf8Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(feature = "transmute_from")]
+mod verified {
+    use core::mem::{Assume, TransmuteFrom};
+
+    /// Implemented if rustc proves that `&Src` can be transmuted to `&Self`, without assuming
+    /// anything
+    #[doc(hidden)]
+    pub unsafe trait TransmutableRef<Src> {}
+    unsafe impl<Src, Dst> TransmutableRef<Src> for Dst where
+        for<'a> &'a Dst: TransmuteFrom<&'a Src, { Assume::NOTHING }>
+    {
+    }
+
+    /// Same as [`TransmutableRef`], except that `Self` is assumed to have no safety invariants
+    #[doc(hidden)]
+    pub unsafe trait TransmutableRefAssumeSafety<Src> {}
+    unsafe impl<Src, Dst> TransmutableRefAssumeSafety<Src> for Dst where
+        for<'a> &'a Dst: TransmuteFrom<&'a Src, { Assume::SAFETY }>
+    {
+    }
+}
+
+#[cfg(feature = "transmute_from")]
+pub use verified::{TransmutableRef, TransmutableRefAssumeSafety};
+
+/// Implements the transmute guards that rustc proves with `core::mem::TransmuteFrom`, e.g.
+/// `transmute_from_guards! { bool => u8; unsafe [u8; 2] => Pair; }`.
+///
+/// `Src => Dst` needs no `unsafe`, but rustc only proves it for types without fields that could
+/// have safety invariants. `unsafe Src => Dst` asserts that `Dst` has no safety invariants and lets
+/// rustc check everything else. The layout is checked like in `impl_transmute_guard!`.
+///
+/// Requires the nightly `transmute_from` feature. A blanket impl over `TransmuteFrom` is not
+/// possible because it would overlap with every other guard.
+#[cfg(feature = "transmute_from")]
+#[macro_export]
+macro_rules! transmute_from_guards {
+    {} => {};
+    { $(#[$attr:meta])* unsafe $From:ty => $To:ty $(; $($rest:tt)*)? } => {
+        $(#[$attr])*
+        const _: () = {
+            const fn check<Src, Dst>()
+            where
+                Dst: $crate::__private::TransmutableRefAssumeSafety<Src>,
+            {
+            }
+            check::<$From, $To>();
+        };
+        $crate::impl_transmute_guard! { $(#[$attr])* unsafe $From => $To }
+        $crate::transmute_from_guards! { $($($rest)*)? }
+    };
+    { $(#[$attr:meta])* $From:ty => $To:ty $(; $($rest:tt)*)? } => {
+        $(#[$attr])*
+        const _: () = {
+            const fn check<Src, Dst>()
+            where
+                Dst: $crate::__private::TransmutableRef<Src>,
+            {
+            }
+            check::<$From, $To>();
+        };
+        $crate::impl_transmute_guard! { $(#[$attr])* unsafe $From => $To }
+        $crate::transmute_from_guards! { $($($rest)*)? }
+    };
+}
+
+/// Implements the transmute guards that rustc proves with `core::mem::TransmuteFrom`, e.g.
+/// `transmute_from_guards! { bool => u8; unsafe [u8; 2] => Pair; }`.
+///
+/// `Src => Dst` needs no `unsafe`, but rustc only proves it for types without fields that could
+/// have safety invariants. `unsafe Src => Dst` asserts that `Dst` has no safety invariants and lets
+/// rustc check everything else. The layout is checked like in `impl_transmute_guard!`.
+///
+/// Requires the nightly `transmute_from` feature. A blanket impl over `TransmuteFrom` is not
+/// possible because it would overlap with every other guard.
+#[cfg(not(feature = "transmute_from"))]
+#[macro_export]
+macro_rules! transmute_from_guards {
+    { $($input:tt)* } => {
+        ::core::compile_error!(
+            "`transmute_from_guards!` requires the `transmute_from` feature of `transmute_guard`"
+        );
+    };
+}
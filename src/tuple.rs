@@ -0,0 +1,122 @@
+use core::alloc::Layout;
+
+use crate::TransmuteGuard;
+
+/// Declares `#[repr(C)]` tuple structs, the safe replacement for transmuting Rust tuples, whose
+/// layout is not guaranteed, e.g.
+/// `repr_c_tuple! { pub struct Pair(pub u32, pub u32) <=> [u32; 2]; }`.
+///
+/// `<=> [T; N]` adds the transmute guards in both directions with the array, which needs every
+/// field to be a `T`. `impl A => B;` adds the guard from `A` to `B` for two of these structs with
+/// the same number of fields, which needs a guard from every field of `A` to the field of `B` at
+/// the same index and fails to compile if that moves a field to a different offset.
+/// `impl A <=> B;` adds both directions. Up to 12 fields are supported.
+#[macro_export]
+macro_rules! repr_c_tuple {
+    {} => {};
+    { impl $from:ty => $to:ty; $($rest:tt)* } => {
+        const _: () = <
+            <$to as $crate::__private::tuple::ReprCTuple>::Fields
+            as $crate::__private::tuple::FieldsGuard<
+                <$from as $crate::__private::tuple::ReprCTuple>::Fields
+            >
+        >::CHECK;
+
+        $crate::impl_transmute_guard! { unsafe $from => $to }
+
+        $crate::repr_c_tuple! { $($rest)* }
+    };
+    { impl $($a:ident)::+ <=> $b:ty; $($rest:tt)* } => {
+        $crate::repr_c_tuple! { impl $($a)::+ => $b; impl $b => $($a)::+; $($rest)* }
+    };
+    {
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident(
+            $($(#[$field_meta:meta])* $field_vis:vis $field_ty:ty),+ $(,)?
+        ) $(<=> [$elem:ty; $len:expr])?;
+        $($rest:tt)*
+    } => {
+        $(#[$meta])*
+        #[repr(C)]
+        $vis struct $name($($(#[$field_meta])* $field_vis $field_ty),+);
+
+        impl $crate::__private::tuple::ReprCTuple for $name {
+            type Fields = ($($field_ty,)+);
+        }
+
+        $(
+            // Only compiles if every field is an `$elem`, so there is no padding between them
+            const _: fn(
+                <<$name as $crate::__private::tuple::ReprCTuple>::Fields
+                    as $crate::__private::tuple::HomogeneousFields>::Array,
+            ) -> [$elem; $len] = |array| array;
+
+            $crate::impl_transmute_guard! { unsafe bidirectional $name <=> [$elem; $len] }
+        )?
+
+        $crate::repr_c_tuple! { $($rest)* }
+    };
+}
+
+/// Implemented by the structs of [`repr_c_tuple!`](crate::repr_c_tuple)
+pub trait ReprCTuple {
+    /// Rust tuple of the field types
+    type Fields;
+}
+
+/// Implemented for Rust tuples of field types if there is a guard from every field of `Src` to
+/// the field of `Self` at the same index
+pub trait FieldsGuard<Src> {
+    /// Fails to evaluate if the fields have different sizes or different offsets in `#[repr(C)]`
+    const CHECK: ();
+}
+
+/// Implemented for Rust tuples whose fields all have the same type
+pub trait HomogeneousFields {
+    type Array;
+}
+
+const fn same_repr_c_offsets(src: &[Layout], dst: &[Layout]) -> bool {
+    let mut src_offset: usize = 0;
+    let mut dst_offset: usize = 0;
+    let mut index = 0;
+    while index < src.len() {
+        src_offset = src_offset.next_multiple_of(src[index].align());
+        dst_offset = dst_offset.next_multiple_of(dst[index].align());
+        if src_offset != dst_offset || src[index].size() != dst[index].size() {
+            return false;
+        }
+        src_offset += src[index].size();
+        dst_offset += dst[index].size();
+        index += 1;
+    }
+    true
+}
+
+macro_rules! impl_tuple_fields {
+    (@impl $(($src:ident $dst:ident))+) => {
+        impl<$($src, $dst),+> FieldsGuard<($($src,)+)> for ($($dst,)+)
+        where
+            $($dst: TransmuteGuard<$src>),+
+        {
+            const CHECK: () = assert!(
+                same_repr_c_offsets(&[$(Layout::new::<$src>()),+], &[$(Layout::new::<$dst>()),+]),
+                "the fields have different sizes or offsets",
+            );
+        }
+
+        impl<T> HomogeneousFields for ($(impl_tuple_fields!(@same T $src),)+) {
+            type Array = [T; 0 $(+ impl_tuple_fields!(@one $src))+];
+        }
+    };
+    (@same $t:ident $src:ident) => { $t };
+    (@one $src:ident) => { 1 };
+    ([$($done:tt)*]) => {};
+    ([$($done:tt)*] $next:tt $($rest:tt)*) => {
+        impl_tuple_fields!(@impl $($done)* $next);
+        impl_tuple_fields!([$($done)* $next] $($rest)*);
+    };
+}
+
+impl_tuple_fields!([] (S0 D0) (S1 D1) (S2 D2) (S3 D3) (S4 D4) (S5 D5) (S6 D6) (S7 D7) (S8 D8)
+    (S9 D9) (S10 D10) (S11 D11));
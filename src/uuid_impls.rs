@@ -0,0 +1,8 @@
+use uuid::Uuid;
+
+use crate::{Pod, Zeroable};
+
+crate::impl_transmute_guard! { unsafe bidirectional uuid::Uuid <=> [u8; 16] }
+
+unsafe impl Zeroable for Uuid {}
+unsafe impl Pod for Uuid {}
@@ -0,0 +1,98 @@
+use core::{marker::PhantomData, ptr::NonNull};
+
+use crate::{get_transmute_mut, CastError, Pod, TransmuteGuard};
+
+/// Exclusive access to a `T` that is only read and written with volatile operations, such as a
+/// memory-mapped register. It never creates a reference to the `T`, so the memory can change
+/// behind it.
+#[derive(Debug)]
+pub struct VolatileRef<'a, T> {
+    ptr: NonNull<T>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> VolatileRef<'a, T> {
+    /// # Safety
+    /// `ptr` must be aligned and valid for volatile reads and writes of `T` for `'a`, and not be
+    /// accessed other than through this for `'a`
+    #[inline]
+    pub const unsafe fn from_ptr(ptr: NonNull<T>) -> Self {
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// # Safety
+    /// Same as [`VolatileRef::from_ptr`], for the pointer with the address `addr`
+    #[inline]
+    pub unsafe fn from_addr(addr: usize) -> Self {
+        unsafe {
+            Self::from_ptr(NonNull::new_unchecked(
+                core::ptr::with_exposed_provenance_mut(addr),
+            ))
+        }
+    }
+
+    #[inline]
+    pub const fn from_mut(value: &'a mut T) -> Self {
+        unsafe { Self::from_ptr(NonNull::from_mut(value)) }
+    }
+
+    /// The `T` at `offset` bytes into `bytes`, which fails if it does not fit or is misaligned
+    #[inline]
+    pub fn from_bytes(bytes: &'a mut [u8], offset: usize) -> Result<Self, CastError>
+    where
+        T: Pod,
+    {
+        get_transmute_mut(bytes, offset).map(Self::from_mut)
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> NonNull<T> {
+        self.ptr
+    }
+
+    #[inline]
+    pub fn read(&self) -> T
+    where
+        T: Copy,
+    {
+        unsafe { self.ptr.read_volatile() }
+    }
+
+    #[inline]
+    pub fn write(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        unsafe { self.ptr.write_volatile(value) }
+    }
+
+    /// Reads the value, then writes the value returned by `f`
+    #[inline]
+    pub fn modify<F>(&mut self, f: F)
+    where
+        T: Copy,
+        F: FnOnce(T) -> T,
+    {
+        self.write(f(self.read()));
+    }
+
+    /// Reborrows for a shorter lifetime
+    #[inline]
+    pub fn reborrow(&mut self) -> VolatileRef<'_, T> {
+        unsafe { VolatileRef::from_ptr(self.ptr) }
+    }
+
+    /// Accesses the same memory as a `Dst`, which needs the guards in both directions like
+    /// mutable references, because the value is written as a `Dst` and read back as a `T`
+    #[inline]
+    pub fn transmute<Dst>(self) -> VolatileRef<'a, Dst>
+    where
+        Dst: TransmuteGuard<T>,
+        T: TransmuteGuard<Dst>,
+    {
+        unsafe { VolatileRef::from_ptr(self.ptr.cast()) }
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::{transmute_unchecked, TransmuteGuard};
+
+/// # Safety
+/// Only implement this trait if `Self` is a `#[repr(transparent)]` wrapper around `Inner`
+pub unsafe trait TransparentWrapper<Inner>: TransmuteGuard<Inner>
+where
+    Inner: ?Sized,
+{
+    #[inline]
+    fn wrap(inner: Inner) -> Self
+    where
+        Self: Sized,
+        Inner: Sized,
+    {
+        unsafe { transmute_unchecked(inner) }
+    }
+
+    #[inline]
+    fn wrap_ref(inner: &Inner) -> &Self {
+        unsafe { transmute_unchecked(inner) }
+    }
+
+    #[inline]
+    fn wrap_mut(inner: &mut Inner) -> &mut Self {
+        unsafe { transmute_unchecked(inner) }
+    }
+
+    #[inline]
+    fn wrap_slice(inner: &[Inner]) -> &[Self]
+    where
+        Self: Sized,
+        Inner: Sized,
+    {
+        unsafe { core::slice::from_raw_parts(inner.as_ptr().cast(), inner.len()) }
+    }
+
+    #[inline]
+    fn wrap_slice_mut(inner: &mut [Inner]) -> &mut [Self]
+    where
+        Self: Sized,
+        Inner: Sized,
+    {
+        unsafe { core::slice::from_raw_parts_mut(inner.as_mut_ptr().cast(), inner.len()) }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn wrap_box(inner: Box<Inner>) -> Box<Self> {
+        let inner_ptr: *mut Inner = Box::into_raw(inner);
+        unsafe { Box::from_raw(transmute_unchecked(inner_ptr)) }
+    }
+
+    #[inline]
+    fn peel(this: Self) -> Inner
+    where
+        Self: Sized,
+        Inner: Sized,
+    {
+        unsafe { transmute_unchecked(this) }
+    }
+
+    #[inline]
+    fn peel_ref(this: &Self) -> &Inner {
+        unsafe { transmute_unchecked(this) }
+    }
+
+    #[inline]
+    fn peel_mut(this: &mut Self) -> &mut Inner {
+        unsafe { transmute_unchecked(this) }
+    }
+
+    #[inline]
+    fn peel_slice(this: &[Self]) -> &[Inner]
+    where
+        Self: Sized,
+        Inner: Sized,
+    {
+        unsafe { core::slice::from_raw_parts(this.as_ptr().cast(), this.len()) }
+    }
+
+    #[inline]
+    fn peel_slice_mut(this: &mut [Self]) -> &mut [Inner]
+    where
+        Self: Sized,
+        Inner: Sized,
+    {
+        unsafe { core::slice::from_raw_parts_mut(this.as_mut_ptr().cast(), this.len()) }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn peel_box(this: Box<Self>) -> Box<Inner> {
+        let this_ptr: *mut Self = Box::into_raw(this);
+        unsafe { Box::from_raw(transmute_unchecked(this_ptr)) }
+    }
+}
+
+/// Declares `#[repr(transparent)]` newtypes with the transmute guards in both directions,
+/// `TransparentWrapper`, `AsRef` and `Borrow` of the inner type and const `wrap` and `peel`
+/// methods, e.g. `transparent_wrapper! { pub struct Meters(pub f32); }`.
+///
+/// `Borrow` lets maps with wrapper keys be queried with the inner type, which needs any `Eq`,
+/// `Ord` and `Hash` of the wrapper to be derived so they agree with the inner type.
+#[macro_export]
+macro_rules! transparent_wrapper {
+    {} => {};
+    {
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($(#[$field_meta:meta])* $field_vis:vis $inner:ty);
+        $($rest:tt)*
+    } => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        $vis struct $name($(#[$field_meta])* $field_vis $inner);
+
+        $crate::impl_transmute_guard! { unsafe bidirectional $name <=> $inner }
+        unsafe impl $crate::TransparentWrapper<$inner> for $name {}
+
+        impl ::core::convert::AsRef<$inner> for $name {
+            #[inline]
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl ::core::borrow::Borrow<$inner> for $name {
+            #[inline]
+            fn borrow(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl $name {
+            #[inline]
+            $vis const fn wrap(inner: $inner) -> Self {
+                $crate::safe_transmute_const(inner)
+            }
+
+            #[inline]
+            $vis const fn wrap_ref(inner: &$inner) -> &Self {
+                $crate::safe_transmute_ref_const(inner)
+            }
+
+            #[inline]
+            $vis const fn wrap_mut(inner: &mut $inner) -> &mut Self {
+                $crate::safe_transmute_mut_const(inner)
+            }
+
+            #[inline]
+            $vis const fn peel(self) -> $inner {
+                $crate::safe_transmute_const(self)
+            }
+
+            #[inline]
+            $vis const fn peel_ref(&self) -> &$inner {
+                $crate::safe_transmute_ref_const(self)
+            }
+
+            #[inline]
+            $vis const fn peel_mut(&mut self) -> &mut $inner {
+                $crate::safe_transmute_mut_const(self)
+            }
+        }
+
+        $crate::transparent_wrapper! { $($rest)* }
+    };
+}
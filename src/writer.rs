@@ -0,0 +1,125 @@
+use crate::pod::bytes_of_slice;
+use crate::{bytes_of, CastError, LengthError, Pod};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Writes the bytes of `Pod` values into a byte buffer, advancing past every value written.
+///
+/// A failed write leaves the buffer and the length unchanged.
+#[derive(Debug)]
+pub struct ByteWriter<'a> {
+    bytes: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> ByteWriter<'a> {
+    #[inline]
+    pub const fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, len: 0 }
+    }
+
+    /// Number of bytes written so far
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn written(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    #[inline]
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+
+    #[inline]
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), CastError> {
+        let rest = &mut self.bytes[self.len..];
+        let len = rest.len();
+        rest.get_mut(..bytes.len())
+            .ok_or(CastError::OutOfBounds(LengthError {
+                len,
+                size: bytes.len(),
+            }))?
+            .copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn push_transmute<T>(&mut self, value: &T) -> Result<(), CastError>
+    where
+        T: Pod,
+    {
+        self.push_bytes(bytes_of(value))
+    }
+
+    #[inline]
+    pub fn push_slice_transmute<T>(&mut self, values: &[T]) -> Result<(), CastError>
+    where
+        T: Pod,
+    {
+        self.push_bytes(bytes_of_slice(values))
+    }
+}
+
+/// Same as [`ByteWriter`], except that the bytes are appended to a `Vec<u8>` that grows as needed
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct ByteVecWriter<'a> {
+    bytes: &'a mut Vec<u8>,
+    start: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> ByteVecWriter<'a> {
+    #[inline]
+    pub fn new(bytes: &'a mut Vec<u8>) -> Self {
+        let start = bytes.len();
+        Self { bytes, start }
+    }
+
+    /// Number of bytes written so far
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len() - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn written(&self) -> &[u8] {
+        &self.bytes[self.start..]
+    }
+
+    #[inline]
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    #[inline]
+    pub fn push_transmute<T>(&mut self, value: &T)
+    where
+        T: Pod,
+    {
+        self.push_bytes(bytes_of(value));
+    }
+
+    #[inline]
+    pub fn push_slice_transmute<T>(&mut self, values: &[T])
+    where
+        T: Pod,
+    {
+        self.push_bytes(bytes_of_slice(values));
+    }
+}
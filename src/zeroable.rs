@@ -0,0 +1,71 @@
+use core::{
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ptr::NonNull,
+};
+
+/// # Safety
+/// Only implement this trait if all zero bytes are a valid `Self`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not `Zeroable`",
+    label = "all zero bytes are not known to be a valid `{Self}`",
+    note = "write an `unsafe impl` if all zero bytes are a valid `{Self}`"
+)]
+pub unsafe trait Zeroable: Sized {}
+
+macro_rules! impl_zeroable {
+    ($($ty:ty),* $(,)?) => {
+        $(unsafe impl Zeroable for $ty {})*
+    };
+}
+impl_zeroable!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_zeroable!(bool, char, ());
+impl_zeroable!(
+    Option<NonZeroU8>,
+    Option<NonZeroU16>,
+    Option<NonZeroU32>,
+    Option<NonZeroU64>,
+    Option<NonZeroU128>,
+    Option<NonZeroUsize>,
+    Option<NonZeroI8>,
+    Option<NonZeroI16>,
+    Option<NonZeroI32>,
+    Option<NonZeroI64>,
+    Option<NonZeroI128>,
+    Option<NonZeroIsize>,
+);
+unsafe impl<T, const N: usize> Zeroable for [T; N] where T: Zeroable {}
+unsafe impl<T> Zeroable for core::num::Wrapping<T> where T: Zeroable {}
+unsafe impl<T> Zeroable for core::num::Saturating<T> where T: Zeroable {}
+unsafe impl<T> Zeroable for ManuallyDrop<T> where T: Zeroable {}
+unsafe impl<T> Zeroable for MaybeUninit<T> {}
+unsafe impl<T> Zeroable for PhantomData<T> where T: ?Sized {}
+unsafe impl<T> Zeroable for *const T {}
+unsafe impl<T> Zeroable for *mut T {}
+unsafe impl<T> Zeroable for Option<NonNull<T>> where T: ?Sized {}
+unsafe impl<T> Zeroable for Option<&T> where T: ?Sized {}
+unsafe impl<T> Zeroable for Option<&mut T> where T: ?Sized {}
+
+#[inline]
+pub const fn zeroed<T>() -> T
+where
+    T: Zeroable,
+{
+    unsafe { core::mem::zeroed() }
+}
+
+#[inline]
+pub fn fill_zero<T>(slice: &mut [T])
+where
+    T: Zeroable,
+{
+    if core::mem::needs_drop::<T>() {
+        slice.iter_mut().for_each(|value| *value = zeroed());
+    } else {
+        unsafe { core::ptr::write_bytes(slice.as_mut_ptr(), 0, slice.len()) }
+    }
+}
@@ -0,0 +1,69 @@
+/// Implements the transmute guards for types that zerocopy has already checked, without `unsafe`,
+/// e.g. `zerocopy_guards! { [u8; 4] => Header; Pod for Header; }`.
+///
+/// `Src => Dst` implements `TransmuteGuard<Src>` and `SafeTransmuteFrom<Src>` for `Dst` if `Src`
+/// is `IntoBytes + Immutable` and `Dst` is `FromBytes + Immutable`, with the layout checks of
+/// `impl_transmute_guard!`. `Pod for T` implements `Zeroable` and `Pod` if `T` is
+/// `FromBytes + IntoBytes + Immutable`.
+///
+/// Requires the `zerocopy` feature, which also derives the zerocopy traits for the types of this
+/// crate that allow them.
+#[cfg(feature = "zerocopy")]
+#[macro_export]
+macro_rules! zerocopy_guards {
+    {} => {};
+    { $(#[$attr:meta])* Pod for $ty:ty $(; $($rest:tt)*)? } => {
+        $(#[$attr])*
+        const _: () = {
+            const fn check<T>()
+            where
+                T: $crate::__private::zerocopy::FromBytes
+                    + $crate::__private::zerocopy::IntoBytes
+                    + $crate::__private::zerocopy::Immutable,
+            {
+            }
+            check::<$ty>();
+        };
+        $(#[$attr])*
+        unsafe impl $crate::Zeroable for $ty {}
+        $(#[$attr])*
+        unsafe impl $crate::Pod for $ty {}
+        $crate::zerocopy_guards! { $($($rest)*)? }
+    };
+    { $(#[$attr:meta])* $From:ty => $To:ty $(; $($rest:tt)*)? } => {
+        $(#[$attr])*
+        const _: () = {
+            const fn check<Src, Dst>()
+            where
+                Src: $crate::__private::zerocopy::IntoBytes
+                    + $crate::__private::zerocopy::Immutable,
+                Dst: $crate::__private::zerocopy::FromBytes
+                    + $crate::__private::zerocopy::Immutable,
+            {
+            }
+            check::<$From, $To>();
+        };
+        $crate::impl_transmute_guard! { $(#[$attr])* unsafe $From => $To }
+        $crate::zerocopy_guards! { $($($rest)*)? }
+    };
+}
+
+/// Implements the transmute guards for types that zerocopy has already checked, without `unsafe`,
+/// e.g. `zerocopy_guards! { [u8; 4] => Header; Pod for Header; }`.
+///
+/// `Src => Dst` implements `TransmuteGuard<Src>` and `SafeTransmuteFrom<Src>` for `Dst` if `Src`
+/// is `IntoBytes + Immutable` and `Dst` is `FromBytes + Immutable`, with the layout checks of
+/// `impl_transmute_guard!`. `Pod for T` implements `Zeroable` and `Pod` if `T` is
+/// `FromBytes + IntoBytes + Immutable`.
+///
+/// Requires the `zerocopy` feature, which also derives the zerocopy traits for the types of this
+/// crate that allow them.
+#[cfg(not(feature = "zerocopy"))]
+#[macro_export]
+macro_rules! zerocopy_guards {
+    { $($input:tt)* } => {
+        ::core::compile_error!(
+            "`zerocopy_guards!` requires the `zerocopy` feature of `transmute_guard`"
+        );
+    };
+}
@@ -0,0 +1,44 @@
+use transmute_guard::{Arena, CastError, LengthError};
+
+#[repr(C, align(8))]
+struct Buf([u8; 32]);
+
+#[test]
+fn alloc() {
+    let mut buf = Buf([0xff; 32]);
+    let mut arena = Arena::new(&mut buf.0);
+    let byte = arena.alloc(1u8).unwrap();
+    let word = arena.alloc(2u32).unwrap();
+    assert_eq!(arena.used(), 8);
+    assert_eq!(core::ptr::from_mut(word).addr() % 4, 0);
+    *byte += 1;
+    *word += 1;
+    assert_eq!((*byte, *word), (2, 3));
+    let zeroed = arena.alloc_zeroed::<u64>().unwrap();
+    assert_eq!(*zeroed, 0);
+    let copied = arena.alloc_slice_copy(&[4u16, 5, 6]).unwrap();
+    assert_eq!(copied, [4, 5, 6]);
+    assert_eq!(arena.alloc_slice_zeroed::<u8>(2).unwrap(), [0, 0]);
+    assert_eq!(arena.used(), 24);
+    assert_eq!(arena.remaining(), 8);
+}
+
+#[test]
+fn alloc_out_of_bounds() {
+    let mut buf = Buf([0; 32]);
+    let mut arena = Arena::new(&mut buf.0[..12]);
+    arena.alloc(1u8).unwrap();
+    assert_eq!(
+        arena.alloc_slice_zeroed::<u32>(3),
+        Err(CastError::OutOfBounds(LengthError { len: 11, size: 15 }))
+    );
+    assert_eq!((arena.used(), arena.remaining()), (1, 11));
+    assert_eq!(arena.alloc_slice_zeroed::<u32>(2).unwrap(), [0, 0]);
+    assert_eq!(
+        arena.alloc_slice_zeroed::<u8>(usize::MAX),
+        Err(CastError::OutOfBounds(LengthError {
+            len: 0,
+            size: usize::MAX,
+        }))
+    );
+}
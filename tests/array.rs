@@ -0,0 +1,16 @@
+use transmute_guard::{flatten_slice, flatten_slice_mut};
+
+#[test]
+fn flatten() {
+    let mut arrays = [[1u16, 2], [3, 4]];
+    assert_eq!(flatten_slice(&arrays), [1, 2, 3, 4]);
+    flatten_slice_mut(&mut arrays)[3] = 5;
+    assert_eq!(arrays, [[1, 2], [3, 5]]);
+    assert_eq!(flatten_slice(&[[(); usize::MAX]]).len(), usize::MAX);
+}
+
+#[test]
+#[should_panic = "slice length overflow"]
+fn flatten_zero_sized_overflow() {
+    flatten_slice(&[[(); usize::MAX]; 2]);
+}
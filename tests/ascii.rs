@@ -0,0 +1,96 @@
+#![cfg_attr(feature = "ascii", feature(ascii_char, ascii_char_variants))]
+
+use transmute_guard::{
+    safe_transmute, safe_transmute_mut, safe_transmute_ref, safe_transmute_slice,
+    try_transmute_slice, try_transmute_slice_mut, AsciiChar, AsciiDigit, AsciiStr,
+};
+
+#[test]
+fn ascii_char_owned() {
+    let char = AsciiChar::new(b'a').unwrap();
+    assert_eq!(safe_transmute::<_, u8>(char), b'a');
+    let chars = [char; 3];
+    assert_eq!(safe_transmute::<_, [u8; 3]>(chars), *b"aaa");
+    let digit = AsciiDigit::new(b'7').unwrap();
+    assert_eq!(
+        safe_transmute::<_, AsciiChar>(digit),
+        AsciiChar::new(b'7').unwrap()
+    );
+}
+
+#[test]
+fn ascii_char_ref() {
+    let char = AsciiChar::new(b'a').unwrap();
+    assert_eq!(*safe_transmute_ref::<_, u8>(&char), b'a');
+    let chars: &[AsciiChar] = try_transmute_slice(b"abc").unwrap();
+    assert_eq!(safe_transmute_slice::<_, u8>(chars), b"abc");
+    assert_eq!(safe_transmute_ref::<_, [u8]>(chars), b"abc");
+    assert_eq!(safe_transmute_ref::<_, str>(chars), "abc");
+}
+
+#[test]
+fn ascii_char_mut() {
+    let mut bytes = *b"abc";
+    let chars: &mut [AsciiChar] = try_transmute_slice_mut(&mut bytes).unwrap();
+    chars[0] = AsciiChar::new(b'z').unwrap();
+    assert_eq!(&bytes, b"zbc");
+    assert!(try_transmute_slice_mut::<u8, AsciiChar>(&mut [0x80]).is_err());
+    let mut str = String::from("abc");
+    let ascii = AsciiStr::try_from_str_mut(&mut str).unwrap();
+    ascii.as_chars_mut()[2] = AsciiChar::new(b'!').unwrap();
+    assert_eq!(str, "ab!");
+    assert!(AsciiStr::try_from_str_mut(&mut String::from("é")).is_err());
+    let mut chars = [AsciiChar::new(b'a').unwrap()];
+    let ascii: &mut AsciiStr = safe_transmute_mut(&mut chars[..]);
+    assert_eq!(ascii, "a");
+}
+
+#[test]
+fn bool_byte() {
+    assert_eq!(safe_transmute::<_, u8>(true), 1);
+    assert_eq!(*safe_transmute_ref::<_, u8>(&false), 0);
+    let mut bytes = [0, 1];
+    let bools: &mut [bool] = try_transmute_slice_mut(&mut bytes).unwrap();
+    bools[0] = true;
+    assert_eq!(bytes, [1, 1]);
+}
+
+#[cfg(feature = "ascii")]
+mod core_ascii {
+    use core::ascii::Char;
+
+    use super::*;
+    use transmute_guard::{try_transmute_str_ascii_chars, try_transmute_str_ascii_chars_mut};
+
+    #[test]
+    fn owned() {
+        assert_eq!(safe_transmute::<_, u8>(Char::SmallA), b'a');
+        assert_eq!(safe_transmute::<_, [u8; 2]>([Char::SmallA; 2]), *b"aa");
+        let char: AsciiChar = safe_transmute(Char::SmallA);
+        assert_eq!(safe_transmute::<_, Char>(char), Char::SmallA);
+    }
+
+    #[test]
+    fn refs() {
+        let chars = [Char::SmallA, Char::SmallB];
+        assert_eq!(*safe_transmute_ref::<_, u8>(&chars[0]), b'a');
+        assert_eq!(safe_transmute_ref::<_, [u8]>(&chars[..]), b"ab");
+        assert_eq!(safe_transmute_ref::<_, str>(&chars[..]), "ab");
+        assert_eq!(try_transmute_str_ascii_chars("ab"), Ok(&chars[..]));
+        assert!(try_transmute_str_ascii_chars("é").is_err());
+    }
+
+    #[test]
+    fn muts() {
+        let mut char = Char::SmallA;
+        let ascii: &mut AsciiChar = safe_transmute_mut(&mut char);
+        *ascii = AsciiChar::new(b'b').unwrap();
+        assert_eq!(char, Char::SmallB);
+        let mut str = String::from("ab");
+        try_transmute_str_ascii_chars_mut(&mut str).unwrap()[0] = Char::Digit1;
+        assert_eq!(str, "1b");
+        let mut bytes = *b"ab";
+        try_transmute_slice_mut::<u8, Char>(&mut bytes).unwrap()[1] = Char::Digit2;
+        assert_eq!(&bytes, b"a2");
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature = "audit", debug_assertions))]
+
+use transmute_guard::{
+    impl_transmute_guard, safe_transmute, safe_transmute_mut, safe_transmute_ref,
+    safe_transmute_ref_const, safe_transmute_slice, SafeTransmuteMutFrom, SafeTransmuteRefFrom,
+    TryFromBytes,
+};
+
+/// Only odd bytes are valid, but the guards below wrongly accept every `u8`
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Odd(u8);
+
+unsafe impl TryFromBytes for Odd {
+    fn validate_bytes(bytes: &[u8]) -> Result<(), usize> {
+        if bytes[0] % 2 == 1 {
+            Ok(())
+        } else {
+            Err(0)
+        }
+    }
+}
+
+impl_transmute_guard! {
+    unsafe bidirectional Odd <=> u8;
+    // Skips the layout checks of the macro, so only the audit catches it
+    unsafe ?Sized u16 => Odd;
+}
+
+#[test]
+fn audit_valid() {
+    assert_eq!(safe_transmute::<_, Odd>(3u8), Odd(3));
+    assert_eq!(*safe_transmute_ref::<_, Odd>(&5u8), Odd(5));
+}
+
+#[test]
+#[should_panic(expected = "audit: the `u8` is not a valid")]
+fn audit_value() {
+    safe_transmute::<_, Odd>(2u8);
+}
+
+#[test]
+#[should_panic(expected = "audit: the `u8` is not a valid")]
+fn audit_ref_trait() {
+    Odd::safe_transmute_ref_from(&2u8);
+}
+
+#[test]
+#[should_panic(expected = "audit: the `u8` is not a valid")]
+fn audit_mut_trait() {
+    let mut value = 4u8;
+    let _: &mut Odd = SafeTransmuteMutFrom::safe_transmute_mut_from(&mut value);
+}
+
+#[test]
+#[should_panic(expected = "audit: the `u8` is not a valid")]
+fn audit_mut() {
+    let mut value = 4u8;
+    let _: &mut Odd = safe_transmute_mut(&mut value);
+}
+
+#[test]
+#[should_panic(expected = "audit: the source and destination have different sizes")]
+fn audit_ref_const() {
+    safe_transmute_ref_const::<u16, Odd>(&1);
+}
+
+#[test]
+#[should_panic(expected = "audit: the source and destination have different sizes")]
+fn audit_slice() {
+    safe_transmute_slice::<u16, Odd>(&[1, 3]);
+}
@@ -0,0 +1,147 @@
+use core::num::NonZeroU16;
+
+use transmute_guard::{
+    bytes_of, try_from_bytes, try_transmute_ascii_str, try_transmute_mut, try_transmute_ref,
+    try_transmute_slice, with_str_bytes_mut, AsciiStr, CastError, LengthError, TryTransmuteRefFrom,
+    ValidityError,
+};
+
+#[repr(C, align(4))]
+struct Aligned<const N: usize>([u8; N]);
+
+#[test]
+fn try_from_bytes_valid() {
+    assert_eq!(*try_from_bytes::<u8>(&[9]).unwrap(), 9);
+    let bools = Aligned([1, 0, 1, 1]);
+    assert_eq!(
+        *try_from_bytes::<[bool; 4]>(&bools.0).unwrap(),
+        [true, false, true, true]
+    );
+    let char = Aligned(u32::from('a').to_ne_bytes());
+    assert_eq!(*try_from_bytes::<char>(&char.0).unwrap(), 'a');
+}
+
+#[test]
+fn try_from_bytes_invalid_offset() {
+    let bools = Aligned([1, 0, 2, 1]);
+    assert_eq!(
+        try_from_bytes::<[bool; 4]>(&bools.0),
+        Err(CastError::Invalid(ValidityError { index: 2 }))
+    );
+    let chars = [u32::from('a'), 0xd800];
+    assert_eq!(
+        try_from_bytes::<[char; 2]>(bytes_of(&chars)),
+        Err(CastError::Invalid(ValidityError { index: 4 }))
+    );
+    let zero = Aligned([0, 0]);
+    assert_eq!(
+        try_from_bytes::<NonZeroU16>(&zero.0),
+        Err(CastError::Invalid(ValidityError { index: 0 }))
+    );
+}
+
+#[test]
+fn try_from_bytes_length() {
+    assert_eq!(
+        try_from_bytes::<[bool; 2]>(&[1]),
+        Err(CastError::LengthMismatch(LengthError { len: 1, size: 2 }))
+    );
+}
+
+#[test]
+fn scan_every_position() {
+    let mut buf = Aligned([0u8; 96]);
+    for start in 0..8 {
+        for end in start..buf.0.len() {
+            let bytes = &mut buf.0[start..end];
+            assert_eq!(
+                try_transmute_slice::<u8, bool>(bytes).unwrap().len(),
+                bytes.len()
+            );
+            for index in 0..bytes.len() {
+                bytes[index] = 2;
+                assert_eq!(
+                    try_transmute_slice::<u8, bool>(bytes).unwrap_err(),
+                    ValidityError { index }
+                );
+                bytes[index] = 0x80;
+                assert_eq!(
+                    try_transmute_ascii_str(bytes).unwrap_err(),
+                    ValidityError { index }
+                );
+                bytes[index] = 0;
+            }
+        }
+    }
+}
+
+#[test]
+fn scan_first_of_many() {
+    let mut bytes = [b'a'; 64];
+    bytes[40] = 0xc0;
+    bytes[50] = 0x80;
+    assert_eq!(
+        try_transmute_ascii_str(&bytes).unwrap_err(),
+        ValidityError { index: 40 }
+    );
+    assert_eq!(
+        try_transmute_ascii_str(&bytes[..40]).unwrap(),
+        "a".repeat(40)
+    );
+}
+
+#[test]
+fn with_str_bytes_mut_restores() {
+    let mut string = String::from("héllo");
+    let str = string.as_mut_str();
+    assert_eq!(with_str_bytes_mut(str, |bytes| bytes[0] = b'j'), Ok(()));
+    assert_eq!(
+        with_str_bytes_mut(str, |bytes| bytes[1] = b'e'),
+        Err(ValidityError { index: 2 })
+    );
+    assert_eq!(string.as_bytes(), b"je\0\0\0\0");
+}
+
+#[test]
+fn with_str_bytes_mut_panic() {
+    let mut string = String::from("héllo");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        with_str_bytes_mut(&mut string, |bytes| {
+            bytes[2] = b'x';
+            panic!("unwinding with invalid UTF-8");
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(string.as_bytes(), b"h\0\0\0\0\0");
+}
+
+#[test]
+fn try_transmute_ref_str() {
+    assert_eq!(try_transmute_ref::<[u8], str>(b"plain"), Ok("plain"));
+    assert_eq!(try_transmute_ref::<[u8], str>("añb".as_bytes()), Ok("añb"));
+    assert_eq!(
+        try_transmute_ref::<[u8], str>(b"ab\xc3("),
+        Err(ValidityError { index: 2 })
+    );
+    let mut bytes = *b"abc";
+    try_transmute_mut::<[u8], str>(&mut bytes)
+        .unwrap()
+        .make_ascii_uppercase();
+    assert_eq!(&bytes, b"ABC");
+    let bools = try_transmute_ref::<[u8], [bool]>(&[1, 0]).unwrap();
+    assert_eq!(bools, [true, false]);
+}
+
+#[test]
+fn try_transmute_ref_ascii() {
+    fn parse<T: TryTransmuteRefFrom<[u8]> + ?Sized>(bytes: &[u8]) -> Result<&T, ValidityError> {
+        T::try_transmute_ref_from(bytes)
+    }
+    assert_eq!(parse::<AsciiStr>(b"key=1").unwrap().as_str(), "key=1");
+    assert_eq!(
+        parse::<AsciiStr>("añ".as_bytes()).unwrap_err(),
+        ValidityError { index: 1 }
+    );
+    assert_eq!(parse::<str>("añ".as_bytes()), Ok("añ"));
+    assert!(try_transmute_ref::<str, AsciiStr>("ok").is_ok());
+}
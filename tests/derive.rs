@@ -0,0 +1,214 @@
+#![cfg(feature = "derive")]
+
+use transmute_guard::{
+    from_bytes, safe_transmute, safe_transmute_mut, safe_transmute_ref, try_from_bytes, Be,
+    CastError, LengthError, Packed, TransmuteGuard, TryFromBytes, ValidityError, View,
+};
+
+#[derive(Debug, PartialEq, TransmuteGuard)]
+#[repr(transparent)]
+pub struct Meters(pub f32);
+
+#[derive(TransmuteGuard)]
+#[repr(transparent)]
+pub struct Wrap<T> {
+    pub inner: T,
+}
+
+#[derive(TransmuteGuard)]
+#[repr(transparent)]
+pub struct Bytes([u8]);
+
+#[test]
+fn transparent() {
+    assert_eq!(safe_transmute::<_, Meters>(1.5f32), Meters(1.5));
+    assert_eq!(*safe_transmute_ref::<_, f32>(&Meters(2.0)), 2.0);
+    let mut value = 2.0f32;
+    safe_transmute_mut::<_, Meters>(&mut value).0 = 3.0;
+    assert_eq!(value, 3.0);
+    assert_eq!(safe_transmute::<_, Wrap<u64>>(7u64).inner, 7);
+}
+
+#[derive(TransmuteGuard)]
+#[repr(transparent)]
+pub struct Text(str);
+
+#[derive(TransmuteGuard)]
+#[repr(transparent)]
+pub struct Shown(dyn core::fmt::Debug);
+
+#[derive(TransmuteGuard)]
+#[repr(transparent)]
+pub struct Items<T>([T]);
+
+#[derive(TransmuteGuard)]
+#[repr(transparent)]
+pub struct MaybeSized<T: ?Sized>(T);
+
+#[test]
+fn transparent_unsized() {
+    let mut buf = [1u8, 2, 3];
+    let bytes: &mut Bytes = safe_transmute_mut(&mut buf[..]);
+    bytes.0[0] = 4;
+    let slice: &mut [u8] = safe_transmute_mut(bytes);
+    slice[1] = 5;
+    let bytes: &Bytes = safe_transmute_ref(&buf[..]);
+    assert_eq!(safe_transmute_ref::<_, [u8]>(bytes), [4, 5, 3]);
+    let text: &Text = safe_transmute_ref("hi");
+    assert_eq!(safe_transmute_ref::<_, str>(text), "hi");
+    let shown: &Shown = safe_transmute_ref::<dyn core::fmt::Debug, _>(&7u8);
+    assert_eq!(format!("{:?}", &shown.0), "7");
+    let items: &Items<u16> = safe_transmute_ref(&[1u16, 2][..]);
+    assert_eq!(items.0.len(), 2);
+    assert_eq!(safe_transmute::<_, MaybeSized<u8>>(6u8).0, 6);
+}
+
+#[repr(C)]
+pub struct RawV1 {
+    pub flag: u8,
+    pub len: u32,
+    pub dist: f32,
+}
+
+#[derive(Debug, PartialEq, TransmuteGuard)]
+#[repr(C)]
+#[transmute_guard(from = "RawV1")]
+pub struct V1 {
+    pub flag: u8,
+    pub len: u32,
+    pub dist: Meters,
+}
+
+#[repr(C)]
+pub struct RawPair(pub bool, pub u16);
+
+#[derive(Debug, PartialEq, TransmuteGuard)]
+#[repr(C)]
+#[transmute_guard(from = RawPair)]
+pub struct Pair(pub u8, pub u16);
+
+#[repr(C, align(8))]
+pub struct RawAligned {
+    pub tag: u8,
+    pub value: u32,
+}
+
+#[derive(Debug, PartialEq, TransmuteGuard)]
+#[repr(C, align(8))]
+#[transmute_guard(from = RawAligned)]
+pub struct Aligned8 {
+    pub tag: u8,
+    pub value: u32,
+}
+
+#[test]
+fn field_wise() {
+    let raw = RawV1 {
+        flag: 1,
+        len: 2,
+        dist: 3.0,
+    };
+    let v1 = V1 {
+        flag: 1,
+        len: 2,
+        dist: Meters(3.0),
+    };
+    assert_eq!(safe_transmute::<_, V1>(raw), v1);
+    assert_eq!(
+        *safe_transmute_ref::<_, Pair>(&RawPair(true, 9)),
+        Pair(1, 9)
+    );
+    assert_eq!(
+        safe_transmute::<_, Aligned8>(RawAligned { tag: 1, value: 2 }),
+        Aligned8 { tag: 1, value: 2 }
+    );
+}
+
+#[derive(Clone, Copy, Packed)]
+#[repr(C, packed)]
+pub struct Wire {
+    pub tag: u8,
+    pub size: u32,
+    pub crc: [u16; 2],
+}
+
+#[test]
+fn packed() {
+    let bytes = [7u8, 1, 0, 0, 0, 2, 0, 3, 0];
+    let mut wire: Wire = safe_transmute(bytes);
+    assert_eq!(wire.tag(), 7);
+    assert_eq!(wire.size(), u32::from_ne_bytes([1, 0, 0, 0]));
+    wire.set_tag(8);
+    assert_eq!(safe_transmute::<_, [u8; 9]>(wire)[0], 8);
+    let wire: &Wire = from_bytes(&bytes);
+    assert_eq!(
+        wire.crc(),
+        [u16::from_ne_bytes([2, 0]), u16::from_ne_bytes([3, 0])]
+    );
+}
+
+#[derive(Debug, PartialEq, TryFromBytes)]
+#[repr(u8)]
+pub enum Kind {
+    A = 1,
+    B = 5,
+}
+
+#[derive(Debug, TryFromBytes)]
+#[repr(C)]
+pub struct Record {
+    pub kind: Kind,
+    pub flags: [bool; 2],
+    pub id: u8,
+    pub value: u32,
+}
+
+#[repr(C, align(4))]
+struct Aligned([u8; 8]);
+
+#[test]
+fn try_from_bytes_derive() {
+    let record: &Record = try_from_bytes(&Aligned([5, 1, 0, 3, 1, 0, 0, 0]).0).unwrap();
+    assert_eq!(record.kind, Kind::B);
+    assert_eq!(record.flags, [true, false]);
+    assert_eq!(
+        (record.id, record.value),
+        (3, u32::from_ne_bytes([1, 0, 0, 0]))
+    );
+    assert_eq!(
+        try_from_bytes::<Record>(&Aligned([2, 1, 0, 3, 1, 0, 0, 0]).0).unwrap_err(),
+        CastError::Invalid(ValidityError { index: 0 })
+    );
+    assert_eq!(
+        try_from_bytes::<Record>(&Aligned([1, 1, 2, 3, 1, 0, 0, 0]).0).unwrap_err(),
+        CastError::Invalid(ValidityError { index: 2 })
+    );
+}
+
+#[allow(dead_code)]
+#[derive(View)]
+pub struct Packet {
+    pub kind: u8,
+    pub flag: bool,
+    pub len: Be<u16>,
+    pub body: [u8; 3],
+}
+
+#[test]
+fn view() {
+    assert_eq!(PacketView::SIZE, 7);
+    let bytes = [1, 1, 0, 9, 4, 5, 6];
+    let view = PacketView::parse(&bytes).unwrap();
+    assert_eq!(
+        (*view.kind, *view.flag, view.len.get(), view.body),
+        (1, true, 9, &[4, 5, 6])
+    );
+    assert_eq!(
+        PacketView::parse(&bytes[1..]).unwrap_err(),
+        CastError::LengthMismatch(LengthError { len: 6, size: 7 })
+    );
+    assert_eq!(
+        PacketView::parse(&[1, 2, 0, 0, 0, 0, 0]).unwrap_err(),
+        CastError::Invalid(ValidityError { index: 1 })
+    );
+}
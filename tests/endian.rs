@@ -0,0 +1,33 @@
+use transmute_guard::{try_cast_slice_mut, AlignmentError, Be, CastError, Le};
+
+#[repr(C, align(8))]
+struct Aligned([u8; 16]);
+
+#[test]
+fn to_native_in_place() {
+    let mut buf = Aligned([0, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 0, 0, 0]);
+    let words: &mut [Be<u32>] = try_cast_slice_mut(&mut buf.0).unwrap();
+    assert_eq!(words[1].get(), 0x100);
+    let values = Be::<u32>::to_native_in_place(words).unwrap();
+    assert_eq!(values, [1, 0x100, 0x1_0000, 0x100_0000]);
+    let words = Be::<u32>::from_native_in_place(values);
+    assert_eq!(words[2].get(), 0x1_0000);
+    assert_eq!(buf.0[..4], [0, 0, 0, 1]);
+}
+
+#[test]
+fn to_native_in_place_misaligned() {
+    let mut buf = Aligned([0; 16]);
+    let halves: &mut [Le<u16>] = try_cast_slice_mut(&mut buf.0[1..9]).unwrap();
+    assert_eq!(
+        Le::<u16>::to_native_in_place(halves),
+        Err(CastError::Misaligned(AlignmentError { align: 2 }))
+    );
+    let mut values = [0x0102u16, 0x0304];
+    let halves = Le::<u16>::from_native_in_place(&mut values);
+    assert_eq!(halves[1].get(), 0x0304);
+    assert_eq!(
+        Le::<u16>::to_native_in_place(halves).unwrap(),
+        [0x0102, 0x0304]
+    );
+}
@@ -0,0 +1,286 @@
+use std::{collections::HashMap, mem::size_of};
+
+use transmute_guard::{enum_alias, safe_transmute, EnumAliasError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Status {
+    A = 1,
+    B = 2,
+    C = 4,
+    D = 8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Code {
+    Low = -5,
+    High = 70000,
+    Mid = 3,
+}
+
+enum_alias! {
+    #[derive(Hash)]
+    pub enum Sub: Status = { B | D };
+
+    #[enum_alias(fn_vis = pub(crate), derive(Debug, PartialEq, Eq, PartialOrd, Ord))]
+    pub enum CodeSub: Code as i32 = { High | Low };
+
+    #[enum_alias(display, from_str)]
+    enum Named: Status = {
+        /// The first variant
+        #[enum_alias(is = is_a)]
+        A |
+        #[cfg(any())]
+        B |
+        #[default]
+        C
+    };
+
+    enum Empty: Status = {};
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Status::from(Sub::D), Status::D);
+    assert_eq!(Sub::try_from(Status::B), Ok(Sub::B));
+    assert_eq!(
+        Sub::try_from_parent(Status::A).unwrap_err().to_string(),
+        "`A` is not a variant of `Sub`"
+    );
+    assert_eq!(safe_transmute::<_, Status>(Sub::B), Status::B);
+    assert_eq!(CodeSub::try_from(Code::Low), Ok(CodeSub::Low));
+    assert!(CodeSub::try_from(Code::Mid).is_err());
+    assert_eq!(CodeSub::High.as_repr(), 70000);
+    assert!(CodeSub::Low < CodeSub::High);
+    assert!(Empty::try_from_parent(Status::A).is_err());
+}
+
+#[test]
+fn discriminants() {
+    assert_eq!(Sub::D.as_u8(), 8);
+    assert_eq!(Sub::try_from_repr(2), Ok(Sub::B));
+    assert_eq!(Sub::try_from(8u8), Ok(Sub::D));
+    const MISSING: Result<Sub, EnumAliasError<u8>> = Sub::try_from_repr(4);
+    assert!(MISSING.is_err());
+    assert!(Sub::try_from_repr(3).is_err());
+    const UNCHECKED: Sub = unsafe { Sub::from_parent_unchecked(Status::D) };
+    assert_eq!(UNCHECKED, Sub::D);
+}
+
+#[test]
+fn named_variants() {
+    assert_eq!(Named::COUNT, 2);
+    assert_eq!(Named::VARIANTS, [Named::A, Named::C]);
+    assert_eq!(Named::iter().collect::<Vec<_>>(), [Named::A, Named::C]);
+    assert_eq!(Empty::iter().count(), 0);
+    assert_eq!(format!("{:>3}", Named::A), "  A");
+    assert_eq!(Named::C.as_str(), "C");
+    assert_eq!("C".parse::<Named>(), Ok(Named::C));
+    assert!("B".parse::<Named>().is_err());
+    assert!(Named::A.is_a() && !Named::C.is_a());
+    assert_eq!(Named::default(), Named::C);
+    assert!(Named::try_from_parent(Status::B).is_err());
+}
+
+#[test]
+fn compare_with_parent() {
+    assert!(Sub::B == Status::B);
+    assert!(Status::D == Sub::D);
+    assert!(Sub::B != Status::D);
+    assert_eq!(*Sub::D.as_parent_ref(), Status::D);
+    assert!(Sub::matches(Status::B) && !Sub::matches(Status::A));
+    let map = HashMap::from([(Sub::B, 1)]);
+    assert_eq!(map.get(&Status::B), Some(&1));
+    assert_eq!(map.get(&Status::A), None);
+    let filtered: Vec<_> = Sub::filter([Status::A, Status::B, Status::D]).collect();
+    assert_eq!(filtered, [Sub::B, Sub::D]);
+}
+
+enum_alias! {
+    enum Mid: Status = { A | B | C };
+
+    #[enum_alias(ancestors(Status))]
+    enum Leaf: Mid = { B | C };
+
+    #[enum_alias(siblings(Third))]
+    enum First: Status = { A | B };
+
+    #[enum_alias(subset_of(First, Third))]
+    enum Second: Status = { B };
+
+    enum Third: Status = { B | C | D };
+}
+
+#[test]
+fn related_aliases() {
+    assert_eq!(Status::from(Leaf::B), Status::B);
+    assert_eq!(Leaf::try_from(Status::C), Ok(Leaf::C));
+    assert!(Leaf::try_from(Status::D).is_err());
+    assert_eq!(Mid::from(Leaf::C), Mid::C);
+    assert_eq!(First::try_from(Third::B), Ok(First::B));
+    assert!(First::try_from(Third::D).is_err());
+    assert_eq!(Third::try_from(First::B), Ok(Third::B));
+    assert_eq!(First::from(Second::B), First::B);
+    assert_eq!(Third::from(Second::B), Third::B);
+    assert!(Second::try_from(First::A).is_err());
+}
+
+enum_alias! {
+    #[enum_alias(display)]
+    {
+        enum Grouped: Status = { A | B } impl {
+            fn is_first(self) -> bool {
+                matches!(self, Self::A)
+            }
+        };
+
+        #[enum_alias(from_str)]
+        enum GroupedRest: Status = { C | D };
+    }
+}
+
+#[test]
+fn batch_and_impl_blocks() {
+    assert!(Grouped::A.is_first() && !Grouped::B.is_first());
+    assert_eq!(Grouped::B.to_string(), "B");
+    assert_eq!("D".parse::<GroupedRest>(), Ok(GroupedRest::D));
+}
+
+enum_alias! {
+    #[enum_alias(set = SubSet as u8)]
+    enum SetAlias: Status = { A | #[cfg(any())] B | C | D };
+}
+
+#[test]
+fn sets() {
+    const ONE: SubSet = SubSet::from_slice(&[SetAlias::C]);
+    let mut set = ONE;
+    assert!(set.contains(SetAlias::C) && !set.contains(SetAlias::D));
+    assert!(set.insert(SetAlias::D));
+    assert!(!set.insert(SetAlias::D));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [SetAlias::C, SetAlias::D]);
+    assert_eq!(set.bits(), 0b110);
+    assert_eq!(SubSet::ALL.len(), 3);
+    assert_eq!(set.complement().iter().collect::<Vec<_>>(), [SetAlias::A]);
+    assert_eq!(SubSet::from_bits(0b1000), None);
+    assert_eq!(SetAlias::D.index(), 2);
+}
+
+enum_alias! {
+    #[enum_alias(set = HandledSet as u8)]
+    enum Handled: Status = { A | B }
+    else #[enum_alias(set = UnhandledSet)] enum Unhandled = { C | D };
+
+    #[enum_alias(set = LoneSet as u8)]
+    enum Lone: Status = { A } else enum LoneRest = { B | C | D };
+}
+
+#[test]
+fn complement_sets() {
+    let handled = HandledSet::from_slice(&[Handled::B]);
+    assert!(handled.contains(Handled::B));
+    assert!(!handled.contains(Handled::A));
+    let unhandled = UnhandledSet::from_slice(&[Unhandled::C, Unhandled::D]);
+    assert!(unhandled.contains(Unhandled::D));
+    assert!(LoneSet::from_slice(&[Lone::A]).contains(Lone::A));
+    assert_eq!(Handled::partition(Status::C), Err(Unhandled::C));
+    assert_eq!(Lone::partition(Status::A), Ok(Lone::A));
+}
+
+#[test]
+fn alias_match() {
+    let classify = |status: Status| {
+        transmute_guard::alias_match!(status => Unhandled: Status {
+            C if status == Status::C => 1,
+            D => 2,
+        } else 0)
+    };
+    assert_eq!(classify(Status::C), 1);
+    assert_eq!(classify(Status::D), 2);
+    assert_eq!(classify(Status::A), 0);
+}
+
+#[cfg(feature = "arbitrary")]
+enum_alias! {
+    #[enum_alias(arbitrary)]
+    enum Arbitrary: Status = { A | C };
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_variants() {
+    use arbitrary::{Arbitrary as _, Unstructured};
+
+    let mut data = Unstructured::new(&[0, 1, 2, 3]);
+    for _ in 0..4 {
+        let value = Arbitrary::arbitrary(&mut data).unwrap();
+        assert!(matches!(value, Arbitrary::A | Arbitrary::C));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Wide {
+    A = 1,
+    B = 2,
+    C = 3,
+    Big = 200,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i8)]
+pub enum Signed {
+    Min = -128,
+    Minus = -1,
+    Zero = 0,
+    Max = 127,
+}
+
+enum_alias! {
+    #[enum_alias(storage = u8, display, set = NarrowSet as u8)]
+    enum Narrow: Wide as u32 = { A | B | C };
+
+    #[enum_alias(storage = u8, subset_of(Narrow))]
+    enum Narrower: Wide as u32 = { B | C };
+
+    #[enum_alias(storage = u8)]
+    enum NarrowMain: Wide as u32 = { A | B | C } else enum NarrowRest = { Big };
+
+    #[enum_alias(storage = i8)]
+    enum NarrowSigned: Signed as i8 = { Minus | Zero };
+
+    enum Ends: Signed as i8 = { Min | Max };
+}
+
+#[test]
+fn narrow_storage() {
+    assert_eq!(size_of::<Narrow>(), 1);
+    assert_eq!(size_of::<Option<Narrow>>(), 1);
+    assert_eq!(Narrow::try_from_parent(Wide::C), Ok(Narrow::C));
+    assert!(Narrow::try_from_parent(Wide::Big).is_err());
+    assert_eq!(Narrow::B.as_parent(), Wide::B);
+    assert_eq!(Narrow::B.as_repr(), 2u32);
+    assert_eq!(Narrow::try_from_repr(3), Ok(Narrow::C));
+    assert!(Narrow::A == Wide::A && Wide::C == Narrow::C);
+    assert_eq!(Narrow::A.to_string(), "A");
+    assert!(NarrowSet::ALL.contains(Narrow::B));
+    assert_eq!(Narrow::from(Narrower::C), Narrow::C);
+    assert!(Narrower::try_from(Narrow::A).is_err());
+    assert_eq!(NarrowMain::partition(Wide::Big), Err(NarrowRest::Big));
+    assert_eq!(NarrowMain::partition(Wide::A), Ok(NarrowMain::A));
+    assert_eq!(
+        NarrowSigned::try_from_parent(Signed::Minus),
+        Ok(NarrowSigned::Minus)
+    );
+    assert!(NarrowSigned::try_from_parent(Signed::Max).is_err());
+}
+
+#[test]
+fn transmute_storage() {
+    assert_eq!(size_of::<Ends>(), 1);
+    assert_eq!(Ends::try_from_parent(Signed::Max), Ok(Ends::Max));
+    assert!(Ends::try_from_parent(Signed::Zero).is_err());
+    assert_eq!(Ends::Min.as_repr(), -128);
+    assert_eq!(safe_transmute::<_, Signed>(Ends::Min), Signed::Min);
+}
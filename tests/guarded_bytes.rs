@@ -0,0 +1,25 @@
+use transmute_guard::{CastError, GuardedBytes};
+
+#[test]
+fn split_to() {
+    let buf = GuardedBytes::new([1, 0, 0, 0, 2, 0, 3, 0, 4, 5, 6, 7]);
+    let first: &u8 = buf.split_to_transmute().unwrap();
+    let second: &[u8] = buf.split_to_transmute_slice(3).unwrap();
+    assert_eq!((*first, second), (1, &[0, 0, 0][..]));
+    assert_eq!(buf.len(), 8);
+    assert!(matches!(
+        buf.split_to_transmute_slice::<u8>(9),
+        Err(CastError::OutOfBounds(_))
+    ));
+    assert_eq!(buf.freeze_as::<u8>().unwrap(), [2, 0, 3, 0, 4, 5, 6, 7]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn freeze_shared() {
+    let buf = GuardedBytes::new(vec![1, 2, 3, 4]);
+    buf.split_to_transmute::<u8>().unwrap();
+    let mut shared = buf.freeze_shared::<u8>().unwrap();
+    let front = shared.split_to(1);
+    assert_eq!((&*front, &*shared), (&[2][..], &[3, 4][..]));
+}
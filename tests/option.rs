@@ -0,0 +1,21 @@
+#![cfg(feature = "alloc")]
+
+use transmute_guard::{safe_transmute_option_box, safe_transmute_option_ref};
+
+#[test]
+fn option_box() {
+    let boxed = safe_transmute_option_box::<[u8; 1], u8>(Some(Box::new([7])));
+    assert_eq!(boxed, Some(Box::new(7)));
+    let boxed = safe_transmute_option_box::<bool, u8>(Some(Box::new(true)));
+    assert_eq!(boxed.as_deref(), Some(&1));
+    assert_eq!(safe_transmute_option_box::<bool, u8>(None), None);
+}
+
+#[test]
+fn option_ref() {
+    assert_eq!(
+        safe_transmute_option_ref::<bool, u8>(Some(&false)),
+        Some(&0)
+    );
+    assert_eq!(safe_transmute_option_ref::<bool, u8>(None), None);
+}
@@ -0,0 +1,89 @@
+use transmute_guard::{
+    bytes_of, from_bytes, impl_pod_byte_guards, read_unaligned_transmute, safe_transmute,
+    safe_transmute_ref, try_cast_slice, try_cast_slice_mut, AlignmentError, CastError, LengthError,
+    Pod, Zeroable,
+};
+
+#[test]
+fn pod_guards() {
+    let value = 0x0102_0304u32;
+    assert_eq!(safe_transmute::<_, [u8; 4]>(value), value.to_ne_bytes());
+    assert_eq!(safe_transmute_ref::<_, [u8; 4]>(&value), bytes_of(&value));
+    assert_eq!(safe_transmute::<_, [u8; 8]>(1.5f64), 1.5f64.to_ne_bytes());
+    assert_eq!(safe_transmute::<[u8; 1], i8>([0xff]), -1);
+    assert_eq!(*safe_transmute_ref::<[u8; 1], u8>(&[7]), 7);
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+unsafe impl Zeroable for Rgb {}
+unsafe impl Pod for Rgb {}
+
+impl_pod_byte_guards! { Rgb }
+
+#[test]
+fn pod_byte_guards() {
+    let rgb = Rgb { r: 1, g: 2, b: 3 };
+    assert_eq!(safe_transmute::<_, [u8; 3]>(rgb), [1, 2, 3]);
+    assert_eq!(*safe_transmute_ref::<_, [u8; 3]>(&rgb), [1, 2, 3]);
+}
+
+#[test]
+fn pod_from_bytes() {
+    let value = 0x0102_0304u32;
+    assert_eq!(*from_bytes::<u32>(bytes_of(&value)), value);
+    let bytes = [0, 1, 2, 3, 4];
+    assert_eq!(
+        read_unaligned_transmute::<u32>(&bytes[1..]),
+        u32::from_ne_bytes([1, 2, 3, 4])
+    );
+}
+
+#[repr(C, align(4))]
+struct Aligned([u8; 12]);
+
+#[test]
+fn try_cast_slice_valid() {
+    let mut buf = Aligned([1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+    let words: &[u32] = try_cast_slice(&buf.0).unwrap();
+    assert_eq!(words.len(), 3);
+    let halves: &[u16] = try_cast_slice(words).unwrap();
+    assert_eq!(halves.len(), 6);
+    let words: &mut [u32] = try_cast_slice_mut(&mut buf.0[4..]).unwrap();
+    words[0] = u32::from_ne_bytes([9; 4]);
+    assert_eq!(buf.0[4..8], [9; 4]);
+    let empty: &[[u8; 0]] = try_cast_slice(&[[0u32; 0]; 3]).unwrap();
+    assert_eq!(empty.len(), 3);
+}
+
+#[test]
+fn try_cast_slice_errors() {
+    let buf = Aligned([0; 12]);
+    assert_eq!(
+        try_cast_slice::<u8, u32>(&buf.0[..6]),
+        Err(CastError::LengthMismatch(LengthError { len: 6, size: 4 }))
+    );
+    assert_eq!(
+        try_cast_slice::<u16, [u8; 3]>(&[0; 4]),
+        Err(CastError::LengthMismatch(LengthError { len: 8, size: 3 }))
+    );
+    assert_eq!(
+        try_cast_slice::<u8, u32>(&buf.0[1..9]),
+        Err(CastError::Misaligned(AlignmentError { align: 4 }))
+    );
+    assert_eq!(
+        try_cast_slice::<u8, [u32; 0]>(&buf.0),
+        Err(CastError::SizeMismatch)
+    );
+    let mut buf = Aligned([0; 12]);
+    assert_eq!(
+        try_cast_slice_mut::<u8, u16>(&mut buf.0[3..7]),
+        Err(CastError::Misaligned(AlignmentError { align: 2 }))
+    );
+}
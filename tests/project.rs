@@ -0,0 +1,32 @@
+use transmute_guard::{project_first_field, FirstField};
+
+#[repr(C)]
+pub struct Link {
+    pub next: usize,
+}
+
+#[repr(C)]
+pub struct Node {
+    pub link: Link,
+    pub value: u32,
+}
+
+#[repr(C)]
+pub struct Pair(pub [u16; 2], pub u8);
+
+project_first_field! {
+    Node => link: Link;
+    Pair => 0: [u16; 2];
+}
+
+#[test]
+fn first_field() {
+    let mut node = Node {
+        link: Link { next: 3 },
+        value: 4,
+    };
+    assert_eq!(node.first_field().next, 3);
+    node.first_field_mut().next = 5;
+    assert_eq!((node.link.next, node.value), (5, 4));
+    assert_eq!(*Pair([1, 2], 3).first_field(), [1, 2]);
+}
@@ -0,0 +1,39 @@
+use transmute_guard::{safe_transmute, scalar_struct, CastError, LengthError};
+
+scalar_struct! {
+    #[derive(Debug, PartialEq)]
+    pub struct Vec3 { pub x: f32, pub y: f32, pub z: f32 }
+
+    pub struct Names { pub first: &'static str, pub last: &'static str }
+}
+
+#[test]
+fn scalar_slices() {
+    assert_eq!(Vec3::SCALARS, 3);
+    let vec = safe_transmute::<_, Vec3>([1.0f32, 2.0, 3.0]);
+    assert_eq!(
+        vec,
+        Vec3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0
+        }
+    );
+    let mut scalars = [0.0f32, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let vecs = Vec3::try_slice_from_scalars_mut(&mut scalars).unwrap();
+    vecs[1].x = 9.0;
+    assert_eq!(Vec3::slice_as_scalars(vecs)[3], 9.0);
+    assert_eq!(
+        Vec3::try_slice_from_scalars(&scalars[1..]).unwrap_err(),
+        CastError::LengthMismatch(LengthError { len: 20, size: 12 })
+    );
+}
+
+#[test]
+fn scalar_lifetimes() {
+    let mut names = ["Ada", "Lovelace"];
+    let names = Names::try_slice_from_scalars_mut(&mut names).unwrap();
+    names[0].first = "Augusta";
+    assert_eq!(Names::slice_as_scalars(names), ["Augusta", "Lovelace"]);
+    assert!(Names::try_slice_from_scalars(&["Ada"]).is_err());
+}
@@ -0,0 +1,16 @@
+#![cfg(feature = "simd")]
+#![feature(portable_simd)]
+
+use core::simd::Simd;
+
+use transmute_guard::{safe_transmute, safe_transmute_mut, transmute_array_to_simd};
+
+#[test]
+fn simd_by_value() {
+    let vector = transmute_array_to_simd([1u32, 2, 3, 4]);
+    assert_eq!(vector, Simd::from_array([1, 2, 3, 4]));
+    assert_eq!(safe_transmute::<_, [u32; 4]>(vector), [1, 2, 3, 4]);
+    let mut vector = vector;
+    safe_transmute_mut::<_, [u32; 4]>(&mut vector)[0] = 5;
+    assert_eq!(vector[0], 5);
+}